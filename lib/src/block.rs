@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::jamo::*;
+use crate::{jamo::*, rules::*};
 use std::fmt::Debug;
 
 /// Errors that can occur when working with Hangul syllable blocks.
@@ -80,7 +80,7 @@ pub enum BlockError {
 /// let decomposed_vec = block.decomposed_vec(&options).unwrap();
 /// assert_eq!(decomposed_vec, vec!['ᄀ', 'ᅡ']);
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HangulBlock {
     pub initial: Jamo,
     pub vowel: Jamo,
@@ -466,7 +466,7 @@ pub enum BlockPushResult {
     NonHangul,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)] // Names improve clarity here
 enum BlockCompositionState {
     /// nothing, waiting for first consonant
@@ -525,8 +525,9 @@ enum BlockCompositionState {
 /// let block_char = composer.block_as_string().unwrap();
 /// assert_eq!(block_char, Some('강'));
 /// ```
-#[derive(Debug, PartialEq, Eq)]
-pub struct BlockComposer {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockComposer<R = StandardRules> {
+    rules: R,
     state: BlockCompositionState,
     initial_first: Option<Jamo>,
     initial_second: Option<Jamo>,
@@ -569,9 +570,25 @@ pub enum BlockPopStatus {
 }
 
 impl BlockComposer {
-    /// Creates a new, empty `BlockComposer`.
+    /// Creates a new, empty `BlockComposer` using the `StandardRules`.
     pub fn new() -> Self {
+        Self::with_rules(StandardRules)
+    }
+
+    /// Creates a `BlockComposer` from an existing `HangulBlock`,
+    /// decomposing it into its constituent Jamo characters.
+    /// Returns an error if decomposition fails.
+    pub fn from_composed_block(block: &HangulBlock) -> Result<Self, BlockError> {
+        Self::from_block_with_rules(block, StandardRules)
+    }
+}
+
+impl<R: CompositionRules> BlockComposer<R> {
+    /// Creates a new, empty `BlockComposer` that combines jamo according to
+    /// the given `CompositionRules`.
+    pub fn with_rules(rules: R) -> Self {
         BlockComposer {
+            rules,
             state: BlockCompositionState::ExpectingInitial,
             initial_first: None,
             initial_second: None,
@@ -602,6 +619,61 @@ impl BlockComposer {
         }
     }
 
+    /// Tries to push a Jamo letter into the `BlockComposer` in an explicit
+    /// position, as typed on 3-beolsik layouts with separate keys for initial
+    /// and final consonants. Unlike `push`, an initial never joins the current
+    /// block as a final and a final never moves to start the next block.
+    ///
+    /// Returns `BlockPushResult::StartNewBlockNoPop` if the letter is an
+    /// initial that must start a new block after this one, and
+    /// `BlockPushResult::InvalidHangul` if the letter cannot be placed in the
+    /// given position of this block. An initial that does not combine with a
+    /// block holding only an initial is `InvalidHangul`, since that block
+    /// cannot be completed as a syllable.
+    /// `BlockPushResult::PopAndStartNewBlock` is never returned.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::block::{BlockComposer, BlockPushResult};
+    /// use hangul_cd::jamo::{Jamo, JamoPosition};
+    ///
+    /// let giyeok = Jamo::from_compatibility_jamo('ㄱ').unwrap();
+    /// let a = Jamo::from_compatibility_jamo('ㅏ').unwrap();
+    ///
+    /// let mut composer = BlockComposer::new();
+    /// composer.push_positioned(&giyeok, JamoPosition::Initial);
+    /// composer.push_positioned(&a, JamoPosition::Vowel);
+    ///
+    /// // An initial after a vowel starts a new block instead of becoming a final
+    /// assert_eq!(
+    ///     composer.push_positioned(&giyeok, JamoPosition::Initial),
+    ///     BlockPushResult::StartNewBlockNoPop
+    /// );
+    /// assert_eq!(
+    ///     composer.push_positioned(&giyeok, JamoPosition::Final),
+    ///     BlockPushResult::Success
+    /// );
+    /// assert_eq!(composer.block_as_string().unwrap(), Some('각'));
+    /// ```
+    pub fn push_positioned(&mut self, letter: &Jamo, position: JamoPosition) -> BlockPushResult {
+        match position {
+            JamoPosition::Initial => self.try_push_positioned_initial(letter),
+            JamoPosition::Vowel => match (letter, &self.state) {
+                (Jamo::Consonant(_) | Jamo::CompositeConsonant(_), _) => {
+                    BlockPushResult::InvalidHangul
+                }
+                (
+                    _,
+                    BlockCompositionState::ExpectingDoubleInitialOrVowel
+                    | BlockCompositionState::ExpectingVowel
+                    | BlockCompositionState::ExpectingCompositeVowelOrFinal,
+                ) => self.push(letter),
+                _ => BlockPushResult::InvalidHangul,
+            },
+            JamoPosition::Final => self.try_push_positioned_final(letter),
+        }
+    }
+
     /// Tries to push a character into the `BlockComposer`. If the character
     /// corresponds to a Hangul Jamo letter, it is pushed into the composer.
     /// If the character is not Hangul, `BlockPushResult::NonHangul` is returned.
@@ -706,6 +778,80 @@ impl BlockComposer {
         }
     }
 
+    /// Returns the letters in the block with double initials, composite
+    /// vowels and composite finals split into their parts in their standard
+    /// order, which is the order they were typed in unless the rules
+    /// accepted a pair in reverse.
+    pub(crate) fn letters(&self) -> Vec<Jamo> {
+        [
+            &self.initial_first,
+            &self.initial_second,
+            &self.vowel_first,
+            &self.vowel_second,
+            &self.final_first,
+            &self.final_second,
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect()
+    }
+
+    fn try_push_positioned_initial(&mut self, letter: &Jamo) -> BlockPushResult {
+        let valid_initial = match letter {
+            Jamo::Consonant(_) => true,
+            Jamo::CompositeConsonant(c) => c.is_valid_initial(),
+            _ => false,
+        };
+        match self.state {
+            BlockCompositionState::ExpectingInitial => self.try_push_initial(letter),
+            BlockCompositionState::ExpectingDoubleInitialOrVowel
+                if matches!(letter, Jamo::Consonant(_)) =>
+            {
+                match self.try_push_double_initial_or_vowel(letter) {
+                    BlockPushResult::Success => BlockPushResult::Success,
+                    _ => BlockPushResult::InvalidHangul,
+                }
+            }
+            BlockCompositionState::ExpectingDoubleInitialOrVowel
+            | BlockCompositionState::ExpectingVowel => BlockPushResult::InvalidHangul,
+            _ if valid_initial => BlockPushResult::StartNewBlockNoPop,
+            _ => BlockPushResult::InvalidHangul,
+        }
+    }
+
+    fn try_push_positioned_final(&mut self, letter: &Jamo) -> BlockPushResult {
+        match (&self.state, letter) {
+            (
+                BlockCompositionState::ExpectingCompositeVowelOrFinal
+                | BlockCompositionState::ExpectingFinal,
+                Jamo::Consonant(_),
+            ) => {
+                self.final_first = Some(letter.clone());
+                self.state = BlockCompositionState::ExpectingCompositeFinal;
+                BlockPushResult::Success
+            }
+            (
+                BlockCompositionState::ExpectingCompositeVowelOrFinal
+                | BlockCompositionState::ExpectingFinal,
+                Jamo::CompositeConsonant(c),
+            ) if c.is_valid_final() => {
+                let (f1, f2) = c.decompose();
+                self.final_first = Some(f1);
+                self.final_second = Some(f2);
+                self.state = BlockCompositionState::ExpectingNextBlock;
+                BlockPushResult::Success
+            }
+            (BlockCompositionState::ExpectingCompositeFinal, Jamo::Consonant(_)) => {
+                match self.try_push_composite_final(letter) {
+                    BlockPushResult::Success => BlockPushResult::Success,
+                    _ => BlockPushResult::InvalidHangul,
+                }
+            }
+            _ => BlockPushResult::InvalidHangul,
+        }
+    }
+
     fn try_push_initial(&mut self, letter: &Jamo) -> BlockPushResult {
         match letter {
             Jamo::Consonant(_) => {
@@ -729,15 +875,18 @@ impl BlockComposer {
     fn try_push_double_initial_or_vowel(&mut self, letter: &Jamo) -> BlockPushResult {
         match letter {
             Jamo::Consonant(c) => match &self.initial_first {
-                Some(Jamo::Consonant(i1)) => {
-                    if i1.combine_for_initial(c).is_some() {
-                        self.initial_second = Some(letter.clone());
+                Some(Jamo::Consonant(i1)) => match self.rules.combine_initial(i1, c) {
+                    // The parts are stored in their standard order, which
+                    // may differ from the order the rules accepted them in
+                    Some(composite) => {
+                        let (i1, i2) = composite.decompose();
+                        self.initial_first = Some(i1);
+                        self.initial_second = Some(i2);
                         self.state = BlockCompositionState::ExpectingVowel;
                         BlockPushResult::Success
-                    } else {
-                        BlockPushResult::InvalidHangul
                     }
-                }
+                    None => BlockPushResult::InvalidHangul,
+                },
                 _ => BlockPushResult::InvalidHangul,
             },
             Jamo::Vowel(_) => {
@@ -777,15 +926,16 @@ impl BlockComposer {
     fn try_push_composite_vowel_or_final(&mut self, letter: &Jamo) -> BlockPushResult {
         match letter {
             Jamo::Vowel(c) => match &self.vowel_first {
-                Some(Jamo::Vowel(v1)) => {
-                    if v1.combine(c).is_some() {
-                        self.vowel_second = Some(letter.clone());
+                Some(Jamo::Vowel(v1)) => match self.rules.combine_vowel(v1, c) {
+                    Some(composite) => {
+                        let (v1, v2) = composite.decompose();
+                        self.vowel_first = Some(v1);
+                        self.vowel_second = Some(v2);
                         self.state = BlockCompositionState::ExpectingFinal;
                         BlockPushResult::Success
-                    } else {
-                        BlockPushResult::InvalidHangul
                     }
-                }
+                    None => BlockPushResult::InvalidHangul,
+                },
                 _ => BlockPushResult::InvalidHangul,
             },
             Jamo::Consonant(_) => {
@@ -837,15 +987,16 @@ impl BlockComposer {
     fn try_push_composite_final(&mut self, letter: &Jamo) -> BlockPushResult {
         match letter {
             Jamo::Consonant(c) => match &self.final_first {
-                Some(Jamo::Consonant(f1)) => {
-                    if f1.combine_for_final(c).is_some() {
-                        self.final_second = Some(letter.clone());
+                Some(Jamo::Consonant(f1)) => match self.rules.combine_final(f1, c) {
+                    Some(composite) => {
+                        let (f1, f2) = composite.decompose();
+                        self.final_first = Some(f1);
+                        self.final_second = Some(f2);
                         self.state = BlockCompositionState::ExpectingNextBlock;
                         BlockPushResult::Success
-                    } else {
-                        BlockPushResult::StartNewBlockNoPop
                     }
-                }
+                    None => BlockPushResult::StartNewBlockNoPop,
+                },
                 _ => BlockPushResult::InvalidHangul,
             },
             Jamo::CompositeConsonant(c) => {
@@ -899,9 +1050,16 @@ impl BlockComposer {
     /// );
     /// ```
     pub fn try_as_complete_block(&self) -> Result<BlockCompletionStatus, BlockError> {
+        // Pairs are combined by the rules if they allow it, and otherwise by
+        // the standard combinations, since a double or composite jamo pushed
+        // as a single letter is stored as its parts whatever the rules
         let initial_optional = match (&self.initial_first, &self.initial_second) {
             (Some(Jamo::Consonant(i1)), Some(Jamo::Consonant(i2))) => {
-                match i1.combine_for_initial(i2) {
+                match self
+                    .rules
+                    .combine_initial(i1, i2)
+                    .or(i1.combine_for_initial(i2))
+                {
                     Some(composite) => Some(Jamo::CompositeConsonant(composite)),
                     None => {
                         return Err(BlockError::JamoInInvalidPosition(
@@ -915,21 +1073,27 @@ impl BlockComposer {
             _ => None,
         };
         let vowel_optional = match (&self.vowel_first, &self.vowel_second) {
-            (Some(Jamo::Vowel(v1)), Some(Jamo::Vowel(v2))) => match v1.combine(v2) {
-                Some(composite) => Some(Jamo::CompositeVowel(composite)),
-                None => {
-                    return Err(BlockError::JamoInInvalidPosition(
-                        Jamo::Vowel(v2.clone()),
-                        JamoPosition::Vowel,
-                    ));
+            (Some(Jamo::Vowel(v1)), Some(Jamo::Vowel(v2))) => {
+                match self.rules.combine_vowel(v1, v2).or(v1.combine(v2)) {
+                    Some(composite) => Some(Jamo::CompositeVowel(composite)),
+                    None => {
+                        return Err(BlockError::JamoInInvalidPosition(
+                            Jamo::Vowel(v2.clone()),
+                            JamoPosition::Vowel,
+                        ));
+                    }
                 }
-            },
+            }
             (Some(v1), None) => Some(v1.clone()),
             _ => None,
         };
         let final_optional = match (&self.final_first, &self.final_second) {
             (Some(Jamo::Consonant(f1)), Some(Jamo::Consonant(f2))) => {
-                match f1.combine_for_final(f2) {
+                match self
+                    .rules
+                    .combine_final(f1, f2)
+                    .or(f1.combine_for_final(f2))
+                {
                     Some(composite) => Some(Jamo::CompositeConsonant(composite)),
                     None => {
                         return Err(BlockError::JamoInInvalidPosition(
@@ -974,11 +1138,15 @@ impl BlockComposer {
         }
     }
 
-    /// Creates a `BlockComposer` from an existing `HangulBlock`,
-    /// decomposing it into its constituent Jamo characters.
-    /// Returns an error if decomposition fails.
-    pub fn from_composed_block(block: &HangulBlock) -> Result<Self, BlockError> {
-        let mut result = BlockComposer::new();
+    /// Returns the rules the composer combines jamo with.
+    pub(crate) fn rules(&self) -> &R {
+        &self.rules
+    }
+
+    /// Creates a `BlockComposer` from an existing `HangulBlock` as with
+    /// `from_composed_block`, using the given rules.
+    pub(crate) fn from_block_with_rules(block: &HangulBlock, rules: R) -> Result<Self, BlockError> {
+        let mut result = BlockComposer::with_rules(rules);
         let (i1, i2, v1, v2, f1, f2) = block.decomposed_tuple()?;
 
         if f2.is_some() {
@@ -1404,4 +1572,63 @@ mod tests {
         let expected = vec!['ㄱ', 'ㅏ', 'ㅄ'];
         assert_eq!(decomposed, expected);
     }
+
+    #[test]
+    fn positioned_push() {
+        let letter = |c| Jamo::from_compatibility_jamo(c).unwrap();
+        let mut composer = BlockComposer::new();
+        assert_eq!(
+            composer.push_positioned(&letter('ㄱ'), JamoPosition::Final),
+            BlockPushResult::InvalidHangul
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄱ'), JamoPosition::Initial),
+            BlockPushResult::Success
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄴ'), JamoPosition::Initial),
+            BlockPushResult::InvalidHangul
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄱ'), JamoPosition::Initial),
+            BlockPushResult::Success
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄴ'), JamoPosition::Initial),
+            BlockPushResult::InvalidHangul
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㅏ'), JamoPosition::Initial),
+            BlockPushResult::InvalidHangul
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㅗ'), JamoPosition::Vowel),
+            BlockPushResult::Success
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㅏ'), JamoPosition::Vowel),
+            BlockPushResult::Success
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄸ'), JamoPosition::Final),
+            BlockPushResult::InvalidHangul
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄹ'), JamoPosition::Final),
+            BlockPushResult::Success
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄴ'), JamoPosition::Final),
+            BlockPushResult::InvalidHangul
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄱ'), JamoPosition::Final),
+            BlockPushResult::Success
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㅣ'), JamoPosition::Vowel),
+            BlockPushResult::InvalidHangul
+        );
+        assert_eq!(composer.block_as_string(), Ok(Some('꽑')));
+    }
 }
@@ -0,0 +1,377 @@
+use std::cmp::Ordering;
+
+use crate::{block::*, jamo::*};
+
+/// A sort key for a string under a `HangulCollation`, for use with
+/// `sort_by_key` or as a database column. Keys compare in the same order as
+/// `HangulCollation::compare` compares the strings they were made from.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CollationKey(Vec<u32>);
+
+// Each char becomes one element of the key: the tier in the top byte keeps
+// non-Hangul text before Hangul and archaic jamo after it, and the initial,
+// vowel and final weights fill the lower three bytes
+const TIER_OTHER: u32 = 0;
+const TIER_HANGUL: u32 = 1 << 24;
+const TIER_ARCHAIC: u32 = 2 << 24;
+
+const CHOSEONG_FILLER: char = '\u{115F}';
+const JUNGSEONG_FILLER: char = '\u{1160}';
+const COMPATIBILITY_FILLER: char = '\u{3164}';
+
+/// A dictionary order for Hangul. Implementors list the initial consonants,
+/// vowels and finals in order, and the provided methods compare jamo,
+/// syllables and strings by those lists.
+///
+/// Strings are compared char by char. Composed syllables and sequences of
+/// conjoining jamo are compared slot by slot; a lone jamo sorts before the
+/// syllables starting with it, and a lone vowel is treated as following a
+/// silent ㅇ. Hangul fillers are ignored. Other characters sort before all
+/// Hangul by codepoint, and archaic jamo sort after all modern Hangul.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::collation::{HangulCollation, NorthKoreanOrder, SouthKoreanOrder};
+///
+/// let mut words = vec!["아기", "까치", "나무", "가방"];
+/// words.sort_by_key(|w| SouthKoreanOrder.key(w));
+/// assert_eq!(words, vec!["가방", "까치", "나무", "아기"]);
+///
+/// // Double consonants and initial ㅇ come last in North Korean order
+/// words.sort_by(|a, b| NorthKoreanOrder.compare(a, b));
+/// assert_eq!(words, vec!["가방", "나무", "까치", "아기"]);
+/// ```
+pub trait HangulCollation {
+    /// The initial consonants in dictionary order, as compatibility jamo.
+    fn initial_order(&self) -> &[char];
+
+    /// The vowels in dictionary order, as compatibility jamo.
+    fn vowel_order(&self) -> &[char];
+
+    /// The final consonants in dictionary order, as compatibility jamo.
+    /// Syllables without a final sort before all of them.
+    fn final_order(&self) -> &[char];
+
+    /// Returns the weight of a jamo in the given position, starting from 1,
+    /// or `None` if the jamo cannot appear in that position.
+    fn jamo_weight(&self, jamo: &Jamo, position: &JamoPosition) -> Option<u32> {
+        let order = match position {
+            JamoPosition::Initial => self.initial_order(),
+            JamoPosition::Vowel => self.vowel_order(),
+            JamoPosition::Final => self.final_order(),
+        };
+        let c = jamo.char_compatibility();
+        order
+            .iter()
+            .position(|o| *o == c)
+            .map(|index| index as u32 + 1)
+    }
+
+    /// Compares two jamo in the given position. Jamo that cannot appear in
+    /// that position sort first.
+    fn compare_jamo(&self, a: &Jamo, b: &Jamo, position: &JamoPosition) -> Ordering {
+        self.jamo_weight(a, position)
+            .cmp(&self.jamo_weight(b, position))
+    }
+
+    /// Compares two syllable blocks by initial, then vowel, then final.
+    fn compare_blocks(&self, a: &HangulBlock, b: &HangulBlock) -> Ordering {
+        block_weights(self, a).cmp(&block_weights(self, b))
+    }
+
+    /// Compares two strings in this dictionary order.
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+
+    /// Returns the collation key for a string.
+    fn key(&self, s: &str) -> CollationKey {
+        let chars: Vec<char> = s.chars().collect();
+        let mut elements = Vec::with_capacity(chars.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let (element, consumed) = element_at(self, &chars[i..]);
+            elements.extend(element);
+            i += consumed;
+        }
+        CollationKey(elements)
+    }
+}
+
+/// The South Korean dictionary order used by the 표준국어대사전, where
+/// double consonants follow their single counterparts. For composed
+/// syllables this is the same as codepoint order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SouthKoreanOrder;
+
+impl HangulCollation for SouthKoreanOrder {
+    fn initial_order(&self) -> &[char] {
+        &[
+            'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ',
+            'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+        ]
+    }
+
+    fn vowel_order(&self) -> &[char] {
+        &[
+            'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ',
+            'ㅝ', 'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+        ]
+    }
+
+    fn final_order(&self) -> &[char] {
+        &[
+            'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ',
+            'ㅀ', 'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+        ]
+    }
+}
+
+/// The North Korean (조선말) dictionary order set out in the 조선말규범집.
+/// Double consonants follow all single consonants, a silent initial ㅇ comes
+/// after every other initial, and composite vowels follow the simple ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NorthKoreanOrder;
+
+impl HangulCollation for NorthKoreanOrder {
+    fn initial_order(&self) -> &[char] {
+        &[
+            'ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ', 'ㄲ',
+            'ㄸ', 'ㅃ', 'ㅆ', 'ㅉ', 'ㅇ',
+        ]
+    }
+
+    fn vowel_order(&self) -> &[char] {
+        &[
+            'ㅏ', 'ㅑ', 'ㅓ', 'ㅕ', 'ㅗ', 'ㅛ', 'ㅜ', 'ㅠ', 'ㅡ', 'ㅣ', 'ㅐ', 'ㅒ', 'ㅔ', 'ㅖ',
+            'ㅚ', 'ㅟ', 'ㅢ', 'ㅘ', 'ㅝ', 'ㅙ', 'ㅞ',
+        ]
+    }
+
+    fn final_order(&self) -> &[char] {
+        &[
+            'ㄱ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ',
+            'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ', 'ㄲ', 'ㅆ',
+        ]
+    }
+}
+
+fn block_weights<C: HangulCollation + ?Sized>(
+    collation: &C,
+    block: &HangulBlock,
+) -> (u32, u32, u32) {
+    let weight = |jamo: &Jamo, position| collation.jamo_weight(jamo, &position).unwrap_or(0);
+    (
+        weight(&block.initial, JamoPosition::Initial),
+        weight(&block.vowel, JamoPosition::Vowel),
+        block
+            .final_optional
+            .as_ref()
+            .map_or(0, |f| weight(f, JamoPosition::Final)),
+    )
+}
+
+fn hangul_element((initial, vowel, final_weight): (u32, u32, u32)) -> u32 {
+    TIER_HANGUL | (initial << 16) | (vowel << 8) | final_weight
+}
+
+/// Returns the key element for the text starting at `chars[0]`, if it is not
+/// ignored, and the number of chars it covers.
+fn element_at<C: HangulCollation + ?Sized>(collation: &C, chars: &[char]) -> (Option<u32>, usize) {
+    let c = chars[0];
+    if let Ok(block) = HangulBlock::from_char(c) {
+        return (Some(hangul_element(block_weights(collation, &block))), 1);
+    }
+    if let Some((block, consumed)) = conjoining_block(chars) {
+        return (
+            Some(hangul_element(block_weights(collation, &block))),
+            consumed,
+        );
+    }
+    if matches!(c, CHOSEONG_FILLER | JUNGSEONG_FILLER | COMPATIBILITY_FILLER) {
+        return (None, 1);
+    }
+    let element = match Character::from_char(c) {
+        Ok(Character::Hangul(jamo)) => hangul_element(lone_jamo_weights(collation, &jamo)),
+        _ => match JamoUnicodeType::evaluate(c) {
+            JamoUnicodeType::NonStandardModern | JamoUnicodeType::NonStandardCompatibility => {
+                TIER_ARCHAIC | c as u32
+            }
+            _ => TIER_OTHER | c as u32,
+        },
+    };
+    (Some(element), 1)
+}
+
+/// Weighs a jamo written on its own. Consonants sort as initials with no
+/// vowel, clusters that cannot be initials sort after their first consonant,
+/// and vowels sort as if written after a silent ㅇ.
+fn lone_jamo_weights<C: HangulCollation + ?Sized>(collation: &C, jamo: &Jamo) -> (u32, u32, u32) {
+    let initial = |jamo: &Jamo| {
+        collation
+            .jamo_weight(jamo, &JamoPosition::Initial)
+            .unwrap_or(0)
+    };
+    match jamo {
+        Jamo::Consonant(_) => (initial(jamo), 0, 0),
+        Jamo::CompositeConsonant(composite) if composite.is_valid_initial() => {
+            (initial(jamo), 0, 0)
+        }
+        Jamo::CompositeConsonant(composite) => (
+            initial(&composite.decompose().0),
+            0,
+            collation
+                .jamo_weight(jamo, &JamoPosition::Final)
+                .unwrap_or(0),
+        ),
+        Jamo::Vowel(_) | Jamo::CompositeVowel(_) => (
+            initial(&Jamo::Consonant(JamoConsonantSingular::Ieung)),
+            collation
+                .jamo_weight(jamo, &JamoPosition::Vowel)
+                .unwrap_or(0),
+            0,
+        ),
+    }
+}
+
+/// Reads a syllable written as conjoining modern jamo (an initial, a vowel
+/// and an optional final), returning it with the number of chars it covers.
+fn conjoining_block(chars: &[char]) -> Option<(HangulBlock, usize)> {
+    let in_range = |c: Option<&char>, range: std::ops::Range<u32>| {
+        c.is_some_and(|c| range.contains(&(*c as u32)))
+    };
+    if !in_range(chars.first(), L_BASE..L_BASE + L_COUNT)
+        || !in_range(chars.get(1), V_BASE..V_BASE + V_COUNT)
+    {
+        return None;
+    }
+    let initial = Jamo::from_modern_jamo(chars[0]).ok()?;
+    let vowel = Jamo::from_modern_jamo(chars[1]).ok()?;
+    if in_range(chars.get(2), T_BASE + 1..T_BASE + T_COUNT) {
+        let final_consonant = Jamo::from_modern_jamo(chars[2]).ok()?;
+        Some((
+            HangulBlock {
+                initial,
+                vowel,
+                final_optional: Some(final_consonant),
+            },
+            3,
+        ))
+    } else {
+        Some((
+            HangulBlock {
+                initial,
+                vowel,
+                final_optional: None,
+            },
+            2,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted<C: HangulCollation>(collation: &C, words: &[&'static str]) -> Vec<&'static str> {
+        let mut words = words.to_vec();
+        words.sort_by_key(|w| collation.key(w));
+        words
+    }
+
+    #[test]
+    fn south_korean_matches_codepoint_order_for_syllables() {
+        let syllables: Vec<char> = (S_BASE..S_BASE + S_COUNT)
+            .step_by(37)
+            .filter_map(char::from_u32)
+            .collect();
+        for pair in syllables.windows(2) {
+            assert_eq!(
+                SouthKoreanOrder.compare(&pair[0].to_string(), &pair[1].to_string()),
+                Ordering::Less,
+                "{} / {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn north_korean_order() {
+        let words = [
+            "아이", "까치", "개", "거울", "나비", "닭", "달", "닦다", "와", "외",
+        ];
+        assert_eq!(
+            sorted(&NorthKoreanOrder, &words),
+            vec![
+                "거울", "개", "나비", "달", "닭", "닦다", "까치", "아이", "외", "와"
+            ]
+        );
+        assert_eq!(
+            sorted(&SouthKoreanOrder, &words),
+            vec![
+                "개", "거울", "까치", "나비", "닦다", "달", "닭", "아이", "와", "외"
+            ]
+        );
+    }
+
+    #[test]
+    fn lone_jamo_and_conjoining_sequences() {
+        let words = [
+            "가",
+            "ㄱ",
+            "ㄳ",
+            "ㄲ",
+            "ㅏ",
+            "아",
+            "a",
+            "\u{1100}\u{1161}\u{11A8}",
+            "ᅀ",
+        ];
+        assert_eq!(
+            sorted(&SouthKoreanOrder, &words),
+            vec![
+                "a",
+                "ㄱ",
+                "ㄳ",
+                "가",
+                "\u{1100}\u{1161}\u{11A8}",
+                "ㄲ",
+                "ㅏ",
+                "아",
+                "ᅀ"
+            ]
+        );
+        assert_eq!(
+            SouthKoreanOrder.key("\u{1100}\u{1161}"),
+            SouthKoreanOrder.key("가")
+        );
+        assert_eq!(
+            SouthKoreanOrder.key("가\u{3164}나"),
+            SouthKoreanOrder.key("가나")
+        );
+    }
+
+    #[test]
+    fn jamo_and_block_comparisons() {
+        let ieung = Jamo::Consonant(JamoConsonantSingular::Ieung);
+        let ssang_giyeok = Jamo::from_compatibility_jamo('ㄲ').unwrap();
+        assert_eq!(
+            SouthKoreanOrder.compare_jamo(&ieung, &ssang_giyeok, &JamoPosition::Initial),
+            Ordering::Greater
+        );
+        assert_eq!(
+            NorthKoreanOrder.compare_jamo(&ieung, &ssang_giyeok, &JamoPosition::Initial),
+            Ordering::Greater
+        );
+        assert_eq!(
+            NorthKoreanOrder.compare_jamo(&ieung, &ssang_giyeok, &JamoPosition::Final),
+            Ordering::Less
+        );
+
+        let a = HangulBlock::from_char('깎').unwrap();
+        let b = HangulBlock::from_char('낙').unwrap();
+        assert_eq!(SouthKoreanOrder.compare_blocks(&a, &b), Ordering::Less);
+        assert_eq!(NorthKoreanOrder.compare_blocks(&a, &b), Ordering::Greater);
+    }
+}
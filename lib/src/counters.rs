@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::numerals::*;
+
+/// Errors that can occur when building number and counter phrases.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CounterError {
+    /// An error related to reading the number itself.
+    #[error("Numeral error: {0}")]
+    NumeralError(#[from] NumeralError),
+
+    /// Occurs when a counter is not present in the `CounterTable`.
+    #[error("Unknown counter '{0}'")]
+    UnknownCounter(String),
+}
+
+/// A Korean counter (classifier) word along with the numeral system it is
+/// read with.
+///
+/// Native-counted words switch to Sino-Korean numerals above `native_limit`,
+/// as is usual for large quantities (ex. 세 명 but 삼백오십 명).
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::counters::Counter;
+/// use hangul_cd::numerals::NumeralSystem;
+///
+/// let people = Counter::new("명", NumeralSystem::Native);
+/// assert_eq!(people.phrase(5).unwrap(), "다섯 명");
+/// assert_eq!(people.phrase(350).unwrap(), "삼백오십 명");
+///
+/// let floors = Counter::new("층", NumeralSystem::Sino);
+/// assert_eq!(floors.phrase(3).unwrap(), "삼 층");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter {
+    /// The counter word itself; ex. 개, 명, 층.
+    pub word: String,
+
+    /// The numeral system normally used with this counter.
+    pub system: NumeralSystem,
+
+    /// The largest number read with native numerals when `system` is
+    /// `NumeralSystem::Native`; larger numbers are read with Sino-Korean
+    /// numerals.
+    pub native_limit: u64,
+}
+
+const DEFAULT_NATIVE_LIMIT: u64 = 99;
+
+impl Counter {
+    /// Creates a new `Counter` using the default native limit of 99.
+    pub fn new(word: &str, system: NumeralSystem) -> Self {
+        Counter {
+            word: word.to_string(),
+            system,
+            native_limit: DEFAULT_NATIVE_LIMIT,
+        }
+    }
+
+    /// Returns the spelled-out number and counter phrase for `n`,
+    /// using the counter's own numeral system.
+    pub fn phrase(&self, n: u64) -> Result<String, CounterError> {
+        let system = if n == 0 || n > self.native_limit {
+            NumeralSystem::Sino
+        } else {
+            self.system.clone()
+        };
+        self.phrase_with_system(n, system)
+    }
+
+    /// Returns the spelled-out number and counter phrase for `n`, forcing
+    /// the given numeral system; ex. 이십 살 rather than 스무 살.
+    /// Zero is always read as the Sino-Korean 영.
+    pub fn phrase_with_system(
+        &self,
+        n: u64,
+        system: NumeralSystem,
+    ) -> Result<String, CounterError> {
+        let number = match system {
+            NumeralSystem::Native if n > 0 => native_korean(n, NativeForm::Attributive)?,
+            _ => sino_korean(n as i128, &SinoKoreanOptions::default()),
+        };
+        Ok(format!("{} {}", number, self.word))
+    }
+}
+
+/// A lookup table of counters, keyed by counter word. The default table
+/// contains common counters and callers can add or replace entries.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::counters::{Counter, CounterTable};
+/// use hangul_cd::numerals::NumeralSystem;
+///
+/// let mut table = CounterTable::default();
+/// assert_eq!(table.phrase(3, "개").unwrap(), "세 개");
+/// assert_eq!(table.phrase(20, "살").unwrap(), "스무 살");
+/// assert_eq!(table.phrase(5, "인분").unwrap(), "오 인분");
+///
+/// // Callers can add their own counters
+/// assert!(table.phrase(2, "판").is_err());
+/// table.insert(Counter::new("판", NumeralSystem::Native));
+/// assert_eq!(table.phrase(2, "판").unwrap(), "두 판");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CounterTable {
+    counters: HashMap<String, Counter>,
+}
+
+const DEFAULT_NATIVE_COUNTERS: [&str; 22] = [
+    "개", "명", "사람", "마리", "살", "시", "시간", "권", "잔", "병", "장", "대", "번", "그릇",
+    "켤레", "벌", "송이", "그루", "가지", "달", "척", "통",
+];
+
+const DEFAULT_SINO_COUNTERS: [&str; 18] = [
+    "분",
+    "초",
+    "층",
+    "인분",
+    "년",
+    "개월",
+    "주",
+    "일",
+    "원",
+    "세",
+    "호",
+    "회",
+    "도",
+    "번지",
+    "쪽",
+    "페이지",
+    "학년",
+    "킬로그램",
+];
+
+impl Default for CounterTable {
+    fn default() -> Self {
+        let mut table = CounterTable::empty();
+        for word in DEFAULT_NATIVE_COUNTERS {
+            table.insert(Counter::new(word, NumeralSystem::Native));
+        }
+        for word in DEFAULT_SINO_COUNTERS {
+            table.insert(Counter::new(word, NumeralSystem::Sino));
+        }
+        table
+    }
+}
+
+impl CounterTable {
+    /// Creates a new `CounterTable` with no counters.
+    pub fn empty() -> Self {
+        CounterTable {
+            counters: HashMap::new(),
+        }
+    }
+
+    /// Inserts a counter into the table, replacing and returning any
+    /// existing counter with the same word.
+    pub fn insert(&mut self, counter: Counter) -> Option<Counter> {
+        self.counters.insert(counter.word.clone(), counter)
+    }
+
+    /// Removes and returns the counter with the given word, if present.
+    pub fn remove(&mut self, word: &str) -> Option<Counter> {
+        self.counters.remove(word)
+    }
+
+    /// Returns the counter with the given word, if present.
+    pub fn get(&self, word: &str) -> Option<&Counter> {
+        self.counters.get(word)
+    }
+
+    /// Returns the spelled-out phrase for `n` of the given counter word,
+    /// choosing the numeral system from the table.
+    /// Returns `CounterError::UnknownCounter` if the word is not in the table.
+    pub fn phrase(&self, n: u64, word: &str) -> Result<String, CounterError> {
+        self.get(word)
+            .ok_or_else(|| CounterError::UnknownCounter(word.to_string()))?
+            .phrase(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_table_picks_numeral_system() {
+        let table = CounterTable::default();
+        let tests = vec![
+            (3, "개", "세 개"),
+            (5, "명", "다섯 명"),
+            (3, "시", "세 시"),
+            (30, "분", "삼십 분"),
+            (20, "살", "스무 살"),
+            (21, "살", "스물한 살"),
+            (3, "층", "삼 층"),
+            (5, "인분", "오 인분"),
+            (0, "개", "영 개"),
+            (100, "명", "백 명"),
+            (1000, "원", "천 원"),
+        ];
+        for (n, word, expected) in tests {
+            assert_eq!(table.phrase(n, word).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn forced_numeral_system() {
+        let table = CounterTable::default();
+        let age = table.get("살").unwrap();
+        assert_eq!(
+            age.phrase_with_system(20, NumeralSystem::Sino).unwrap(),
+            "이십 살"
+        );
+        assert_eq!(
+            age.phrase_with_system(20, NumeralSystem::Native).unwrap(),
+            "스무 살"
+        );
+    }
+
+    #[test]
+    fn custom_native_limit() {
+        let mut table = CounterTable::empty();
+        table.insert(Counter {
+            word: "시".to_string(),
+            system: NumeralSystem::Native,
+            native_limit: 12,
+        });
+        assert_eq!(table.phrase(12, "시").unwrap(), "열두 시");
+        assert_eq!(table.phrase(13, "시").unwrap(), "십삼 시");
+    }
+
+    #[test]
+    fn unknown_counter() {
+        let mut table = CounterTable::default();
+        assert!(table.remove("개").is_some());
+        assert_eq!(
+            table.phrase(1, "개"),
+            Err(CounterError::UnknownCounter("개".to_string()))
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::numerals::*;
+
+/// The script used to write a formal monetary amount.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormalAmountStyle {
+    /// Sino-Korean numerals in Hangul; ex. 금 일십이만삼천원정.
+    Hangul,
+
+    /// Ordinary Hanja numerals, with the unit written 圓; ex. 金 一十二萬三千圓整.
+    Hanja,
+
+    /// Anti-forgery Hanja numerals (갖은자), which cannot be altered by
+    /// adding strokes, with the unit written 圓; ex. 金 壹拾貳萬參仟圓整.
+    GajeunHanja,
+}
+
+/// Options for formatting monetary amounts in the formal style used on
+/// checks, contracts and receipts.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::currency::{formal_amount, FormalAmountOptions, FormalAmountStyle};
+///
+/// let opts = FormalAmountOptions {
+///     style: FormalAmountStyle::GajeunHanja,
+///     include_figure: true,
+/// };
+/// assert_eq!(formal_amount(123000, &opts), "金 壹拾貳萬參仟圓整 (₩123,000)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormalAmountOptions {
+    /// The script used to write the amount.
+    pub style: FormalAmountStyle,
+
+    /// Whether to append the amount in figures after the written amount,
+    /// as in 금 일십이만삼천원정 (₩123,000).
+    pub include_figure: bool,
+}
+
+impl Default for FormalAmountOptions {
+    fn default() -> Self {
+        FormalAmountOptions {
+            style: FormalAmountStyle::Hangul,
+            include_figure: false,
+        }
+    }
+}
+
+const HANJA_GLYPHS: SinoGlyphs = SinoGlyphs {
+    digits: ["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"],
+    small_units: ["", "十", "百", "千"],
+    large_units: ["", "萬", "億", "兆", "京", "垓", "秭", "穰", "溝", "澗"],
+};
+
+const GAJEUN_HANJA_GLYPHS: SinoGlyphs = SinoGlyphs {
+    digits: ["零", "壹", "貳", "參", "肆", "伍", "陸", "柒", "捌", "玖"],
+    small_units: ["", "拾", "佰", "仟"],
+    large_units: ["", "萬", "億", "兆", "京", "垓", "秭", "穰", "溝", "澗"],
+};
+
+const CURRENCY_SYMBOL: char = '₩';
+
+/// Formats an amount of won in the formal style used on checks, contracts
+/// and receipts: the amount is prefixed with 금, every digit including 일 is
+/// written out without spaces, and the amount is closed with 정 so that
+/// nothing can be appended to it. Each style is written in a single script,
+/// so the Hanja styles use 圓 for 원 and 整 for 정, and zero is written 영
+/// or 零.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::currency::{formal_amount, FormalAmountOptions, FormalAmountStyle};
+///
+/// let mut opts = FormalAmountOptions::default();
+/// assert_eq!(formal_amount(123000, &opts), "금 일십이만삼천원정");
+///
+/// opts.style = FormalAmountStyle::Hanja;
+/// assert_eq!(formal_amount(123000, &opts), "金 一十二萬三千圓整");
+/// ```
+pub fn formal_amount(amount: u64, options: &FormalAmountOptions) -> String {
+    let (prefix, unit, suffix, glyphs) = match options.style {
+        FormalAmountStyle::Hangul => ("금", "원", "정", &HANGUL_GLYPHS),
+        FormalAmountStyle::Hanja => ("金", "圓", "整", &HANJA_GLYPHS),
+        FormalAmountStyle::GajeunHanja => ("金", "圓", "整", &GAJEUN_HANJA_GLYPHS),
+    };
+    let numeral_options = SinoKoreanOptions {
+        space_by_myriad: false,
+        explicit_one: true,
+    };
+    let written = sino_korean_unsigned(amount as u128, &numeral_options, glyphs);
+
+    let mut result = format!("{prefix} {written}{unit}{suffix}");
+    if options.include_figure {
+        result.push_str(&format!(
+            " ({}{})",
+            CURRENCY_SYMBOL,
+            group_thousands(amount)
+        ));
+    }
+    result
+}
+
+fn group_thousands(amount: u64) -> String {
+    let digits = amount.to_string();
+    let mut result = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            result.push(',');
+        }
+        result.push(c);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formal_hangul_amounts() {
+        let opts = FormalAmountOptions::default();
+        let tests = vec![
+            (0, "금 영원정"),
+            (10, "금 일십원정"),
+            (10000, "금 일만원정"),
+            (1500000, "금 일백오십만원정"),
+            (100000001, "금 일억일원정"),
+        ];
+        for (amount, expected) in tests {
+            assert_eq!(formal_amount(amount, &opts), expected);
+        }
+    }
+
+    #[test]
+    fn formal_gajeun_hanja_amounts() {
+        let opts = FormalAmountOptions {
+            style: FormalAmountStyle::GajeunHanja,
+            include_figure: false,
+        };
+        assert_eq!(formal_amount(1111, &opts), "金 壹仟壹佰壹拾壹圓整");
+        assert_eq!(
+            formal_amount(987654321, &opts),
+            "金 玖億捌仟柒佰陸拾伍萬肆仟參佰貳拾壹圓整"
+        );
+    }
+
+    #[test]
+    fn zero_amounts() {
+        let tests = [
+            (FormalAmountStyle::Hangul, "금 영원정"),
+            (FormalAmountStyle::Hanja, "金 零圓整"),
+            (FormalAmountStyle::GajeunHanja, "金 零圓整"),
+        ];
+        for (style, expected) in tests {
+            let opts = FormalAmountOptions {
+                style,
+                include_figure: true,
+            };
+            assert_eq!(formal_amount(0, &opts), format!("{expected} (₩0)"));
+        }
+    }
+
+    #[test]
+    fn figures_are_grouped_by_thousands() {
+        let opts = FormalAmountOptions {
+            style: FormalAmountStyle::Hangul,
+            include_figure: true,
+        };
+        assert_eq!(formal_amount(999, &opts), "금 구백구십구원정 (₩999)");
+        assert_eq!(
+            formal_amount(1234567, &opts),
+            "금 일백이십삼만사천오백육십칠원정 (₩1,234,567)"
+        );
+    }
+}
@@ -0,0 +1,439 @@
+use thiserror::Error;
+
+use crate::counters::*;
+use crate::numerals::*;
+
+/// Errors that can occur when formatting dates and times in Korean.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DateTimeError {
+    /// An error related to spelling out numbers with counters.
+    #[error("Counter error: {0}")]
+    CounterError(#[from] CounterError),
+
+    /// Occurs when a year, month and day do not form a valid Gregorian date.
+    #[error("Invalid date {0:04}-{1:02}-{2:02}")]
+    InvalidDate(u32, u8, u8),
+
+    /// Occurs when an hour, minute or second is out of range.
+    #[error("Invalid time {0:02}:{1:02}:{2:02}")]
+    InvalidTime(u8, u8, u8),
+}
+
+/// How numbers are written when formatting dates and times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateTimeStyle {
+    /// Numbers are written as digits attached to their unit;
+    /// ex. 2026년 10월 16일, 오후 3시 15분.
+    Digits,
+
+    /// Numbers are spelled out in Hangul and spaced from their unit,
+    /// using the spoken month names 유월 and 시월;
+    /// ex. 이천이십육 년 시월 십육 일, 오후 세 시 십오 분.
+    Hangul,
+}
+
+/// Whether times are written on a 12-hour clock with 오전/오후 or on a
+/// 24-hour clock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HourCycle {
+    /// 12-hour clock with 오전 or 오후; hours are read with native
+    /// numerals (세 시).
+    H12,
+
+    /// 24-hour clock; hours are read with Sino-Korean numerals (십오 시).
+    H24,
+}
+
+/// Options for formatting dates and times in Korean.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::datetime::{CalendarDate, DateTimeFormatOptions, DateTimeStyle, HourCycle};
+///
+/// let date = CalendarDate::new(2026, 6, 5).unwrap();
+/// let opts = DateTimeFormatOptions {
+///     style: DateTimeStyle::Hangul,
+///     include_weekday: false,
+///     hour_cycle: HourCycle::H12,
+/// };
+/// assert_eq!(date.format(&opts).unwrap(), "이천이십육 년 유월 오 일");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimeFormatOptions {
+    /// Whether to write numbers as digits or spelled out in Hangul.
+    pub style: DateTimeStyle,
+
+    /// Whether to append the day of the week to dates.
+    pub include_weekday: bool,
+
+    /// Whether to use a 12-hour or 24-hour clock for times.
+    pub hour_cycle: HourCycle,
+}
+
+impl Default for DateTimeFormatOptions {
+    fn default() -> Self {
+        DateTimeFormatOptions {
+            style: DateTimeStyle::Digits,
+            include_weekday: true,
+            hour_cycle: HourCycle::H12,
+        }
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the Korean name of the day of the week; ex. 금요일.
+    pub fn korean_name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "월요일",
+            Weekday::Tuesday => "화요일",
+            Weekday::Wednesday => "수요일",
+            Weekday::Thursday => "목요일",
+            Weekday::Friday => "금요일",
+            Weekday::Saturday => "토요일",
+            Weekday::Sunday => "일요일",
+        }
+    }
+}
+
+/// A date in the proleptic Gregorian calendar.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::datetime::{CalendarDate, DateTimeFormatOptions, DateTimeStyle, Weekday};
+///
+/// let date = CalendarDate::new(2026, 10, 16).unwrap();
+/// assert_eq!(date.weekday(), Weekday::Friday);
+///
+/// let mut opts = DateTimeFormatOptions::default();
+/// assert_eq!(date.format(&opts).unwrap(), "2026년 10월 16일 금요일");
+///
+/// opts.style = DateTimeStyle::Hangul;
+/// assert_eq!(date.format(&opts).unwrap(), "이천이십육 년 시월 십육 일 금요일");
+///
+/// // Invalid dates are rejected
+/// assert!(CalendarDate::new(2026, 2, 29).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarDate {
+    year: u32,
+    month: u8,
+    day: u8,
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "일월",
+    "이월",
+    "삼월",
+    "사월",
+    "오월",
+    "유월",
+    "칠월",
+    "팔월",
+    "구월",
+    "시월",
+    "십일월",
+    "십이월",
+];
+
+impl CalendarDate {
+    /// Creates a new `CalendarDate`, returning `DateTimeError::InvalidDate`
+    /// if the year is zero or the month or day is out of range.
+    pub fn new(year: u32, month: u8, day: u8) -> Result<Self, DateTimeError> {
+        if year == 0 || !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(DateTimeError::InvalidDate(year, month, day));
+        }
+        Ok(CalendarDate { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the day of the week for this date.
+    pub fn weekday(&self) -> Weekday {
+        // Sakamoto's method; 0 is Sunday
+        const OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let y = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let index =
+            (y + y / 4 - y / 100 + y / 400 + OFFSETS[self.month as usize - 1] + self.day as u32)
+                % 7;
+        match index {
+            0 => Weekday::Sunday,
+            1 => Weekday::Monday,
+            2 => Weekday::Tuesday,
+            3 => Weekday::Wednesday,
+            4 => Weekday::Thursday,
+            5 => Weekday::Friday,
+            _ => Weekday::Saturday,
+        }
+    }
+
+    /// Formats the date in Korean according to the given options.
+    pub fn format(&self, options: &DateTimeFormatOptions) -> Result<String, DateTimeError> {
+        let mut parts = match options.style {
+            DateTimeStyle::Digits => vec![
+                format!("{}년", self.year),
+                format!("{}월", self.month),
+                format!("{}일", self.day),
+            ],
+            DateTimeStyle::Hangul => vec![
+                Counter::new("년", NumeralSystem::Sino).phrase(self.year as u64)?,
+                MONTH_NAMES[self.month as usize - 1].to_string(),
+                Counter::new("일", NumeralSystem::Sino).phrase(self.day as u64)?,
+            ],
+        };
+        if options.include_weekday {
+            parts.push(self.weekday().korean_name().to_string());
+        }
+        Ok(parts.join(" "))
+    }
+}
+
+fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+fn days_in_month(year: u32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A time of day, with an optional seconds component.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::datetime::{ClockTime, DateTimeFormatOptions, DateTimeStyle, HourCycle};
+///
+/// let time = ClockTime::new(15, 15, None).unwrap();
+///
+/// let mut opts = DateTimeFormatOptions::default();
+/// assert_eq!(time.format(&opts).unwrap(), "오후 3시 15분");
+///
+/// opts.style = DateTimeStyle::Hangul;
+/// assert_eq!(time.format(&opts).unwrap(), "오후 세 시 십오 분");
+///
+/// opts.hour_cycle = HourCycle::H24;
+/// assert_eq!(time.format(&opts).unwrap(), "십오 시 십오 분");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockTime {
+    hour: u8,
+    minute: u8,
+    second: Option<u8>,
+}
+
+impl ClockTime {
+    /// Creates a new `ClockTime` from a 24-hour clock hour, returning
+    /// `DateTimeError::InvalidTime` if any component is out of range.
+    pub fn new(hour: u8, minute: u8, second: Option<u8>) -> Result<Self, DateTimeError> {
+        if hour > 23 || minute > 59 || second.is_some_and(|s| s > 59) {
+            return Err(DateTimeError::InvalidTime(
+                hour,
+                minute,
+                second.unwrap_or(0),
+            ));
+        }
+        Ok(ClockTime {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Returns the hour on a 24-hour clock.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, if present.
+    pub fn second(&self) -> Option<u8> {
+        self.second
+    }
+
+    /// Formats the time in Korean according to the given options.
+    /// Minutes are omitted when they are zero and no seconds are present;
+    /// ex. 오후 세 시.
+    pub fn format(&self, options: &DateTimeFormatOptions) -> Result<String, DateTimeError> {
+        let mut parts = Vec::new();
+        let (hour, hour_system) = match options.hour_cycle {
+            HourCycle::H12 => {
+                parts.push(if self.hour < 12 { "오전" } else { "오후" }.to_string());
+                let hour = match self.hour % 12 {
+                    0 => 12,
+                    h => h,
+                };
+                (hour, NumeralSystem::Native)
+            }
+            HourCycle::H24 => (self.hour, NumeralSystem::Sino),
+        };
+
+        let mut units = vec![(hour, "시", hour_system)];
+        if self.minute != 0 || self.second.is_some() {
+            units.push((self.minute, "분", NumeralSystem::Sino));
+        }
+        if let Some(second) = self.second {
+            units.push((second, "초", NumeralSystem::Sino));
+        }
+
+        for (n, word, system) in units {
+            parts.push(match options.style {
+                DateTimeStyle::Digits => format!("{n}{word}"),
+                DateTimeStyle::Hangul => {
+                    Counter::new(word, system.clone()).phrase_with_system(n as u64, system)?
+                }
+            });
+        }
+        Ok(parts.join(" "))
+    }
+}
+
+/// Formats a date followed by a time in Korean according to the given options.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::datetime::{format_date_time, CalendarDate, ClockTime, DateTimeFormatOptions};
+///
+/// let date = CalendarDate::new(2026, 10, 16).unwrap();
+/// let time = ClockTime::new(9, 5, None).unwrap();
+/// assert_eq!(
+///     format_date_time(&date, &time, &DateTimeFormatOptions::default()).unwrap(),
+///     "2026년 10월 16일 금요일 오전 9시 5분"
+/// );
+/// ```
+pub fn format_date_time(
+    date: &CalendarDate,
+    time: &ClockTime,
+    options: &DateTimeFormatOptions,
+) -> Result<String, DateTimeError> {
+    Ok(format!(
+        "{} {}",
+        date.format(options)?,
+        time.format(options)?
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hangul_opts() -> DateTimeFormatOptions {
+        DateTimeFormatOptions {
+            style: DateTimeStyle::Hangul,
+            include_weekday: false,
+            hour_cycle: HourCycle::H12,
+        }
+    }
+
+    #[test]
+    fn weekdays() {
+        let tests = vec![
+            ((2026, 10, 16), Weekday::Friday),
+            ((2000, 1, 1), Weekday::Saturday),
+            ((2024, 2, 29), Weekday::Thursday),
+            ((1970, 1, 1), Weekday::Thursday),
+            ((2023, 12, 31), Weekday::Sunday),
+        ];
+        for ((y, m, d), expected) in tests {
+            assert_eq!(CalendarDate::new(y, m, d).unwrap().weekday(), expected);
+        }
+    }
+
+    #[test]
+    fn invalid_dates() {
+        for (y, m, d) in [(0, 1, 1), (2025, 0, 1), (2025, 13, 1), (2025, 4, 31)] {
+            assert_eq!(
+                CalendarDate::new(y, m, d),
+                Err(DateTimeError::InvalidDate(y, m, d))
+            );
+        }
+        assert!(CalendarDate::new(1900, 2, 29).is_err());
+        assert!(CalendarDate::new(2000, 2, 29).is_ok());
+    }
+
+    #[test]
+    fn hangul_month_names() {
+        let opts = hangul_opts();
+        let tests = vec![
+            (1, "이천이십육 년 일월 일 일"),
+            (6, "이천이십육 년 유월 일 일"),
+            (10, "이천이십육 년 시월 일 일"),
+            (12, "이천이십육 년 십이월 일 일"),
+        ];
+        for (month, expected) in tests {
+            let date = CalendarDate::new(2026, month, 1).unwrap();
+            assert_eq!(date.format(&opts).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn twelve_hour_times() {
+        let opts = hangul_opts();
+        let tests = vec![
+            ((0, 0), "오전 열두 시"),
+            ((12, 0), "오후 열두 시"),
+            ((1, 1), "오전 한 시 일 분"),
+            ((15, 30), "오후 세 시 삼십 분"),
+            ((23, 59), "오후 열한 시 오십구 분"),
+        ];
+        for ((h, m), expected) in tests {
+            let time = ClockTime::new(h, m, None).unwrap();
+            assert_eq!(time.format(&opts).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn times_with_seconds() {
+        let time = ClockTime::new(0, 0, Some(5)).unwrap();
+        let mut opts = hangul_opts();
+        opts.hour_cycle = HourCycle::H24;
+        assert_eq!(time.format(&opts).unwrap(), "영 시 영 분 오 초");
+        opts.style = DateTimeStyle::Digits;
+        assert_eq!(time.format(&opts).unwrap(), "0시 0분 5초");
+    }
+
+    #[test]
+    fn invalid_times() {
+        assert_eq!(
+            ClockTime::new(24, 0, None),
+            Err(DateTimeError::InvalidTime(24, 0, 0))
+        );
+        assert!(ClockTime::new(23, 60, None).is_err());
+        assert!(ClockTime::new(23, 59, Some(60)).is_err());
+    }
+}
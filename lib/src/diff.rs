@@ -0,0 +1,423 @@
+use std::ops::Range;
+
+use crate::{block::*, jamo::*};
+
+/// The kind of change made to a single jamo or character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditKind {
+    /// The jamo or character only appears in the new string.
+    Insertion,
+
+    /// The jamo or character only appears in the old string.
+    Deletion,
+
+    /// The jamo or character was replaced by another in the same slot.
+    Substitution,
+}
+
+/// A change to a single jamo, or to a non-Hangul character, within a hunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotEdit {
+    /// The kind of change.
+    pub kind: EditKind,
+
+    /// The slot of the changed jamo within its syllable, or `None` for a
+    /// character outside any syllable, including lone jamo.
+    pub slot: Option<JamoPosition>,
+
+    /// The jamo or character in the old string, as a compatibility jamo for
+    /// Hangul. `None` for insertions.
+    pub old: Option<char>,
+
+    /// The jamo or character in the new string, as a compatibility jamo for
+    /// Hangul. `None` for deletions.
+    pub new: Option<char>,
+}
+
+/// A group of edits touching the same syllables, together with the char
+/// ranges of those syllables in the old and new strings. A range is empty
+/// when the hunk adds or removes whole characters, in which case it marks
+/// where they were added or removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    /// The range of char indices in the old string covered by the hunk.
+    pub old_chars: Range<usize>,
+
+    /// The range of char indices in the new string covered by the hunk.
+    pub new_chars: Range<usize>,
+
+    /// The edits in the hunk, in string order.
+    pub edits: Vec<SlotEdit>,
+}
+
+/// Options for diffing strings at the jamo level.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JamoDiffOptions {
+    /// Whether double and composite jamo are split into their parts, so
+    /// that ㅆ and ㅅ differ by the deletion of a ㅅ rather than by a
+    /// substitution.
+    pub decompose_composites: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Unit {
+    c: char,
+    slot: Option<JamoPosition>,
+    char_index: usize,
+}
+
+impl Unit {
+    fn same_slot(&self, other: &Unit) -> bool {
+        self.slot == other.slot
+    }
+}
+
+/// Aligns two strings at the jamo level and returns the differences between
+/// them as hunks, one for each group of syllables that changed.
+///
+/// Each syllable is split into its initial, vowel and final, and jamo are
+/// only substituted for jamo in the same slot, so a changed final is never
+/// reported as a changed initial. Characters outside syllables are compared
+/// as-is. Returns no hunks if the strings are equal.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::diff::{jamo_diff, EditKind, JamoDiffOptions, SlotEdit};
+/// use hangul_cd::jamo::JamoPosition;
+///
+/// let hunks = jamo_diff("갔다", "갓다", &JamoDiffOptions::default());
+/// assert_eq!(hunks.len(), 1);
+/// assert_eq!(hunks[0].old_chars, 0..1);
+/// assert_eq!(hunks[0].new_chars, 0..1);
+/// assert_eq!(
+///     hunks[0].edits,
+///     vec![SlotEdit {
+///         kind: EditKind::Substitution,
+///         slot: Some(JamoPosition::Final),
+///         old: Some('ㅆ'),
+///         new: Some('ㅅ'),
+///     }]
+/// );
+/// ```
+pub fn jamo_diff(old: &str, new: &str, options: &JamoDiffOptions) -> Vec<DiffHunk> {
+    let a = units(old, options.decompose_composites);
+    let b = units(new, options.decompose_composites);
+
+    // d[i][j] is the edit distance between the first i units of a and the
+    // first j units of b
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let mut best = (d[i - 1][j] + 1).min(d[i][j - 1] + 1);
+            if a[i - 1].same_slot(&b[j - 1]) {
+                let cost = usize::from(a[i - 1].c != b[j - 1].c);
+                best = best.min(d[i - 1][j - 1] + cost);
+            }
+            d[i][j] = best;
+        }
+    }
+
+    // Walk back through the table, preferring substitutions so that changed
+    // jamo are reported in place
+    let mut steps = Vec::new();
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 || j > 0 {
+        if i > 0
+            && j > 0
+            && a[i - 1].same_slot(&b[j - 1])
+            && d[i][j] == d[i - 1][j - 1] + usize::from(a[i - 1].c != b[j - 1].c)
+        {
+            steps.push((Some(i - 1), Some(j - 1)));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            steps.push((Some(i - 1), None));
+            i -= 1;
+        } else {
+            steps.push((None, Some(j - 1)));
+            j -= 1;
+        }
+    }
+    steps.reverse();
+
+    let old_len = old.chars().count();
+    let new_len = new.chars().count();
+    hunks(&a, &b, &steps, old_len, new_len)
+}
+
+fn hunks(
+    a: &[Unit],
+    b: &[Unit],
+    steps: &[(Option<usize>, Option<usize>)],
+    old_len: usize,
+    new_len: usize,
+) -> Vec<DiffHunk> {
+    // The chars of each string that share aligned jamo with a char of the
+    // other, so that an edit covers the whole syllable on both sides
+    let mut old_partners: Vec<Option<Range<usize>>> = vec![None; old_len];
+    let mut new_partners: Vec<Option<Range<usize>>> = vec![None; new_len];
+    for (x, y) in steps {
+        if let (Some(x), Some(y)) = (x, y) {
+            let (x, y) = (a[*x].char_index, b[*y].char_index);
+            extend(&mut old_partners[x], y);
+            extend(&mut new_partners[y], x);
+        }
+    }
+
+    let mut result: Vec<DiffHunk> = Vec::new();
+    let (mut next_old, mut next_new) = (0, 0);
+    for (x, y) in steps {
+        let x = x.map(|x| &a[x]);
+        let y = y.map(|y| &b[y]);
+        if let Some(x) = x {
+            next_old = x.char_index + 1;
+        }
+        if let Some(y) = y {
+            next_new = y.char_index + 1;
+        }
+        let kind = match (x, y) {
+            (Some(x), Some(y)) if x.c == y.c => continue,
+            (Some(_), Some(_)) => EditKind::Substitution,
+            (Some(_), None) => EditKind::Deletion,
+            _ => EditKind::Insertion,
+        };
+
+        let mut old_chars: Option<Range<usize>> = None;
+        let mut new_chars: Option<Range<usize>> = None;
+        if let Some(x) = x {
+            extend(&mut old_chars, x.char_index);
+            merge(&mut new_chars, &old_partners[x.char_index]);
+        }
+        if let Some(y) = y {
+            extend(&mut new_chars, y.char_index);
+            merge(&mut old_chars, &new_partners[y.char_index]);
+        }
+        let old_chars = old_chars.unwrap_or(next_old..next_old);
+        let new_chars = new_chars.unwrap_or(next_new..next_new);
+        let edit = SlotEdit {
+            kind,
+            slot: x.or(y).and_then(|unit| unit.slot.clone()),
+            old: x.map(|unit| unit.c),
+            new: y.map(|unit| unit.c),
+        };
+
+        match result.last_mut() {
+            Some(hunk)
+                if overlaps(&hunk.old_chars, &old_chars)
+                    || overlaps(&hunk.new_chars, &new_chars) =>
+            {
+                hunk.old_chars = hunk.old_chars.start.min(old_chars.start)
+                    ..hunk.old_chars.end.max(old_chars.end);
+                hunk.new_chars = hunk.new_chars.start.min(new_chars.start)
+                    ..hunk.new_chars.end.max(new_chars.end);
+                hunk.edits.push(edit);
+            }
+            _ => result.push(DiffHunk {
+                old_chars,
+                new_chars,
+                edits: vec![edit],
+            }),
+        }
+    }
+    result
+}
+
+fn extend(range: &mut Option<Range<usize>>, index: usize) {
+    merge(range, &Some(index..index + 1));
+}
+
+fn merge(range: &mut Option<Range<usize>>, other: &Option<Range<usize>>) {
+    *range = match (range.take(), other) {
+        (Some(r), Some(o)) => Some(r.start.min(o.start)..r.end.max(o.end)),
+        (r, o) => r.or(o.clone()),
+    };
+}
+
+fn overlaps(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+fn units(s: &str, decompose_composites: bool) -> Vec<Unit> {
+    let mut result = Vec::new();
+    for (char_index, c) in s.chars().enumerate() {
+        let letters = match HangulBlock::from_char(c) {
+            Ok(block) => {
+                let mut letters = vec![
+                    (block.initial, Some(JamoPosition::Initial)),
+                    (block.vowel, Some(JamoPosition::Vowel)),
+                ];
+                letters.extend(
+                    block
+                        .final_optional
+                        .map(|jamo| (jamo, Some(JamoPosition::Final))),
+                );
+                letters
+            }
+            Err(_) => match Character::from_char(c) {
+                Ok(Character::Hangul(jamo)) => vec![(jamo, None)],
+                _ => {
+                    result.push(Unit {
+                        c,
+                        slot: None,
+                        char_index,
+                    });
+                    continue;
+                }
+            },
+        };
+        for (letter, slot) in letters {
+            let parts = match letter {
+                Jamo::CompositeConsonant(composite) if decompose_composites => {
+                    let (x, y) = composite.decompose();
+                    vec![x, y]
+                }
+                Jamo::CompositeVowel(composite) if decompose_composites => {
+                    let (x, y) = composite.decompose();
+                    vec![x, y]
+                }
+                letter => vec![letter],
+            };
+            result.extend(parts.into_iter().map(|part| Unit {
+                c: part.char_compatibility(),
+                slot: slot.clone(),
+                char_index,
+            }));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(
+        kind: EditKind,
+        slot: Option<JamoPosition>,
+        old: Option<char>,
+        new: Option<char>,
+    ) -> SlotEdit {
+        SlotEdit {
+            kind,
+            slot,
+            old,
+            new,
+        }
+    }
+
+    #[test]
+    fn equal_strings() {
+        let opts = JamoDiffOptions::default();
+        assert_eq!(jamo_diff("", "", &opts), vec![]);
+        assert_eq!(jamo_diff("한국어 OK", "한국어 OK", &opts), vec![]);
+    }
+
+    #[test]
+    fn slot_edits() {
+        let opts = JamoDiffOptions::default();
+        let hunks = jamo_diff("가방", "갈방", &opts);
+        assert_eq!(
+            hunks,
+            vec![DiffHunk {
+                old_chars: 0..1,
+                new_chars: 0..1,
+                edits: vec![edit(
+                    EditKind::Insertion,
+                    Some(JamoPosition::Final),
+                    None,
+                    Some('ㄹ')
+                )],
+            }]
+        );
+
+        let hunks = jamo_diff("학교에", "핵꾜", &opts);
+        assert_eq!(hunks.len(), 3);
+        assert_eq!(
+            (hunks[0].old_chars.clone(), hunks[0].new_chars.clone()),
+            (0..1, 0..1)
+        );
+        assert_eq!(
+            hunks[1].edits,
+            vec![edit(
+                EditKind::Substitution,
+                Some(JamoPosition::Initial),
+                Some('ㄱ'),
+                Some('ㄲ')
+            )]
+        );
+        assert_eq!(
+            (hunks[2].old_chars.clone(), hunks[2].new_chars.clone()),
+            (2..3, 2..2)
+        );
+        assert_eq!(hunks[2].edits.len(), 2);
+    }
+
+    #[test]
+    fn jamo_moved_between_syllables() {
+        let opts = JamoDiffOptions::default();
+        let hunks = jamo_diff("사랑", "살앙", &opts);
+        let ranges: Vec<(Range<usize>, Range<usize>)> = hunks
+            .iter()
+            .map(|hunk| (hunk.old_chars.clone(), hunk.new_chars.clone()))
+            .collect();
+        assert_eq!(ranges, vec![(0..1, 0..1), (1..2, 1..2)]);
+        assert_eq!(
+            hunks[0].edits,
+            vec![edit(
+                EditKind::Insertion,
+                Some(JamoPosition::Final),
+                None,
+                Some('ㄹ')
+            )]
+        );
+        assert_eq!(
+            hunks[1].edits,
+            vec![edit(
+                EditKind::Substitution,
+                Some(JamoPosition::Initial),
+                Some('ㄹ'),
+                Some('ㅇ')
+            )]
+        );
+    }
+
+    #[test]
+    fn whole_characters() {
+        let opts = JamoDiffOptions::default();
+        let hunks = jamo_diff("a가b", "a나가b!", &opts);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(
+            (hunks[0].old_chars.clone(), hunks[0].new_chars.clone()),
+            (1..1, 1..2)
+        );
+        assert_eq!(
+            (hunks[1].old_chars.clone(), hunks[1].new_chars.clone()),
+            (3..3, 4..5)
+        );
+        assert_eq!(
+            hunks[1].edits,
+            vec![edit(EditKind::Insertion, None, None, Some('!'))]
+        );
+    }
+
+    #[test]
+    fn composites_decomposed() {
+        let opts = JamoDiffOptions {
+            decompose_composites: true,
+        };
+        let hunks = jamo_diff("갔다", "갓다", &opts);
+        assert_eq!(
+            hunks[0].edits,
+            vec![edit(
+                EditKind::Deletion,
+                Some(JamoPosition::Final),
+                Some('ㅅ'),
+                None
+            )]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{block::*, jamo::*};
+
+/// The costs of each edit operation used by `jamo_distance`.
+///
+/// Substitutions cost `substitution` by default, but callers can make
+/// specific pairs of jamo cheaper or more expensive; for example, ㅐ and ㅔ
+/// are pronounced the same by most speakers and are often confused.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::distance::EditWeights;
+/// use hangul_cd::jamo::Jamo;
+///
+/// let ae = Jamo::from_compatibility_jamo('ㅐ').unwrap();
+/// let e = Jamo::from_compatibility_jamo('ㅔ').unwrap();
+/// let a = Jamo::from_compatibility_jamo('ㅏ').unwrap();
+///
+/// let mut weights = EditWeights::default();
+/// weights.set_substitution(&ae, &e, 0.3);
+/// assert_eq!(weights.substitution_cost(&e, &ae), 0.3);
+/// assert_eq!(weights.substitution_cost(&ae, &a), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditWeights {
+    /// The cost of inserting a jamo.
+    pub insertion: f64,
+
+    /// The cost of deleting a jamo.
+    pub deletion: f64,
+
+    /// The cost of substituting one jamo for another when no specific
+    /// cost has been set for the pair.
+    pub substitution: f64,
+
+    /// The cost of swapping two adjacent jamo.
+    pub transposition: f64,
+
+    substitutions: Vec<(Jamo, Jamo, f64)>,
+}
+
+impl Default for EditWeights {
+    fn default() -> Self {
+        EditWeights {
+            insertion: 1.0,
+            deletion: 1.0,
+            substitution: 1.0,
+            transposition: 1.0,
+            substitutions: Vec::new(),
+        }
+    }
+}
+
+const SIMILAR_VOWELS: [(char, char); 5] = [
+    ('ㅐ', 'ㅔ'),
+    ('ㅒ', 'ㅖ'),
+    ('ㅙ', 'ㅞ'),
+    ('ㅚ', 'ㅞ'),
+    ('ㅙ', 'ㅚ'),
+];
+
+const SIMILAR_VOWEL_COST: f64 = 0.5;
+
+impl EditWeights {
+    /// Creates `EditWeights` with unit costs, except that vowels which are
+    /// commonly pronounced the same (ㅐ/ㅔ, ㅒ/ㅖ and ㅙ/ㅚ/ㅞ) cost half as
+    /// much to substitute.
+    pub fn similar_vowels() -> Self {
+        let mut weights = EditWeights::default();
+        for (a, b) in SIMILAR_VOWELS {
+            if let (Ok(a), Ok(b)) = (
+                Jamo::from_compatibility_jamo(a),
+                Jamo::from_compatibility_jamo(b),
+            ) {
+                weights.set_substitution(&a, &b, SIMILAR_VOWEL_COST);
+            }
+        }
+        weights
+    }
+
+    /// Sets the cost of substituting `a` for `b` and `b` for `a`, replacing
+    /// any cost previously set for the pair.
+    pub fn set_substitution(&mut self, a: &Jamo, b: &Jamo, cost: f64) {
+        self.substitutions
+            .retain(|(x, y, _)| !((x == a && y == b) || (x == b && y == a)));
+        self.substitutions.push((a.clone(), b.clone(), cost));
+    }
+
+    /// Returns the cost of substituting `a` for `b`. Identical jamo cost
+    /// nothing to substitute.
+    pub fn substitution_cost(&self, a: &Jamo, b: &Jamo) -> f64 {
+        if a == b {
+            return 0.0;
+        }
+        self.substitutions
+            .iter()
+            .find(|(x, y, _)| (x == a && y == b) || (x == b && y == a))
+            .map_or(self.substitution, |(_, _, cost)| *cost)
+    }
+}
+
+/// Options for computing edit distances with `jamo_distance`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditDistanceOptions {
+    /// Whether double consonants, consonant clusters and composite vowels
+    /// are split into their parts before comparing, so that 과 and 고 differ
+    /// by one insertion rather than one substitution.
+    pub decompose_composites: bool,
+
+    /// Whether swapping two adjacent jamo counts as a single edit
+    /// (Damerau-Levenshtein) rather than two.
+    pub transpositions: bool,
+
+    /// The costs of each edit operation.
+    pub weights: EditWeights,
+}
+
+impl Default for EditDistanceOptions {
+    fn default() -> Self {
+        EditDistanceOptions {
+            decompose_composites: true,
+            transpositions: true,
+            weights: EditWeights::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Unit {
+    Letter(Jamo),
+    Other(char),
+}
+
+/// Returns the edit distance between two strings, computed over their
+/// decomposed jamo rather than whole syllables. Changing the final of 간 to
+/// make 갈 costs one edit, while 간 and 호 are three edits apart.
+/// Non-Hangul characters are compared as-is.
+///
+/// Transpositions follow the optimal string alignment variant of
+/// Damerau-Levenshtein: adjacent jamo can be swapped, but a swapped pair is
+/// not edited again.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::distance::{jamo_distance, EditDistanceOptions, EditWeights};
+///
+/// let opts = EditDistanceOptions::default();
+/// assert_eq!(jamo_distance("간", "갈", &opts), 1.0);
+/// assert_eq!(jamo_distance("간", "호", &opts), 3.0);
+///
+/// // Similar vowels can be made cheaper to confuse
+/// let opts = EditDistanceOptions {
+///     weights: EditWeights::similar_vowels(),
+///     ..Default::default()
+/// };
+/// assert_eq!(jamo_distance("개", "게", &opts), 0.5);
+/// ```
+pub fn jamo_distance(a: &str, b: &str, options: &EditDistanceOptions) -> f64 {
+    let a = units(a, options.decompose_composites);
+    let b = units(b, options.decompose_composites);
+    let weights = &options.weights;
+
+    // d[i][j] is the distance between the first i units of a and the first
+    // j units of b
+    let mut d = vec![vec![0.0; b.len() + 1]; a.len() + 1];
+    for i in 1..=a.len() {
+        d[i][0] = d[i - 1][0] + weights.deletion;
+    }
+    for j in 1..=b.len() {
+        d[0][j] = d[0][j - 1] + weights.insertion;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = match (&a[i - 1], &b[j - 1]) {
+                (Unit::Letter(x), Unit::Letter(y)) => weights.substitution_cost(x, y),
+                (x, y) if x == y => 0.0,
+                _ => weights.substitution,
+            };
+            let mut best = (d[i - 1][j] + weights.deletion)
+                .min(d[i][j - 1] + weights.insertion)
+                .min(d[i - 1][j - 1] + substitution);
+            if options.transpositions
+                && i > 1
+                && j > 1
+                && a[i - 1] == b[j - 2]
+                && a[i - 2] == b[j - 1]
+                && a[i - 1] != a[i - 2]
+            {
+                best = best.min(d[i - 2][j - 2] + weights.transposition);
+            }
+            d[i][j] = best;
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+fn units(s: &str, decompose_composites: bool) -> Vec<Unit> {
+    let mut result = Vec::new();
+    for c in s.chars() {
+        let letters = match HangulBlock::from_char(c) {
+            Ok(block) => {
+                let mut letters = vec![block.initial, block.vowel];
+                letters.extend(block.final_optional);
+                letters
+            }
+            Err(_) => match Character::from_char(c) {
+                Ok(Character::Hangul(jamo)) => vec![jamo],
+                _ => {
+                    result.push(Unit::Other(c));
+                    continue;
+                }
+            },
+        };
+        for letter in letters {
+            match letter {
+                Jamo::CompositeConsonant(composite) if decompose_composites => {
+                    let (x, y) = composite.decompose();
+                    result.extend([Unit::Letter(x), Unit::Letter(y)]);
+                }
+                Jamo::CompositeVowel(composite) if decompose_composites => {
+                    let (x, y) = composite.decompose();
+                    result.extend([Unit::Letter(x), Unit::Letter(y)]);
+                }
+                _ => result.push(Unit::Letter(letter)),
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_cost_distances() {
+        let opts = EditDistanceOptions::default();
+        let tests = vec![
+            ("", "", 0.0),
+            ("한국", "한국", 0.0),
+            ("", "가", 2.0),
+            ("간", "갈", 1.0),
+            ("간", "호", 3.0),
+            ("사랑", "살앙", 1.0),
+            ("hello", "hallo", 1.0),
+            ("한국 2", "한국 3", 1.0),
+        ];
+        for (a, b, expected) in tests {
+            assert_eq!(jamo_distance(a, b, &opts), expected, "{a} / {b}");
+        }
+    }
+
+    #[test]
+    fn composite_decomposition() {
+        let mut opts = EditDistanceOptions::default();
+        assert_eq!(jamo_distance("과", "고", &opts), 1.0);
+        assert_eq!(jamo_distance("닭", "달", &opts), 1.0);
+        assert_eq!(jamo_distance("까", "가", &opts), 1.0);
+        assert_eq!(jamo_distance("과", "가", &opts), 1.0);
+
+        opts.decompose_composites = false;
+        assert_eq!(jamo_distance("과", "고", &opts), 1.0);
+        assert_eq!(jamo_distance("닭", "달", &opts), 1.0);
+        assert_eq!(jamo_distance("과", "거", &opts), 1.0);
+        assert_eq!(jamo_distance("와", "ㅘ", &opts), 1.0);
+    }
+
+    #[test]
+    fn transpositions() {
+        let mut opts = EditDistanceOptions::default();
+        // ㅎㅏㄴㄱㅜㄱ vs ㅎㅏㄱㄴㅜㄱ
+        assert_eq!(jamo_distance("한국", "학눅", &opts), 1.0);
+
+        opts.transpositions = false;
+        assert_eq!(jamo_distance("한국", "학눅", &opts), 2.0);
+    }
+
+    #[test]
+    fn custom_weights() {
+        let mut weights = EditWeights::similar_vowels();
+        weights.insertion = 2.0;
+        let mut opts = EditDistanceOptions {
+            weights,
+            ..Default::default()
+        };
+        assert_eq!(jamo_distance("세계", "새걔", &opts), 1.0);
+        assert_eq!(jamo_distance("가", "간", &opts), 2.0);
+        assert_eq!(jamo_distance("간", "가", &opts), 1.0);
+
+        // Composite vowel weights only apply when composites are kept whole
+        assert_eq!(jamo_distance("왜", "웨", &opts), 1.5);
+        opts.decompose_composites = false;
+        assert_eq!(jamo_distance("왜", "웨", &opts), 0.5);
+    }
+}
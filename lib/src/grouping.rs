@@ -0,0 +1,303 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{block::*, collation::*, jamo::*};
+
+/// The index header an entry is listed under, as in the ㄱ, ㄴ, ㄷ … A, B, C
+/// … # side index of a contact list.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IndexBucket {
+    /// Entries starting with a Hangul syllable or jamo, grouped by initial
+    /// consonant.
+    Hangul(Jamo),
+
+    /// Entries starting with a Latin letter, grouped by its uppercase form.
+    /// Accented letters are grouped under their base letter, so Éclair is
+    /// listed under E.
+    Latin(char),
+
+    /// Entries starting with a digit.
+    Digit,
+
+    /// Entries starting with anything else, including empty entries and
+    /// letters with no base letter from A to Z, such as Þ or µ.
+    Symbol,
+}
+
+impl IndexBucket {
+    /// Returns the header text shown for this bucket.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::grouping::{index_bucket, IndexGroupingOptions};
+    ///
+    /// let opts = IndexGroupingOptions::default();
+    /// assert_eq!(index_bucket("김철수", &opts).label(), "ㄱ");
+    /// assert_eq!(index_bucket("alice", &opts).label(), "A");
+    /// assert_eq!(index_bucket("010-1234", &opts).label(), "0-9");
+    /// assert_eq!(index_bucket("@home", &opts).label(), "#");
+    /// ```
+    pub fn label(&self) -> String {
+        match self {
+            IndexBucket::Hangul(jamo) => jamo.char_compatibility().to_string(),
+            IndexBucket::Latin(c) => c.to_string(),
+            IndexBucket::Digit => "0-9".to_string(),
+            IndexBucket::Symbol => "#".to_string(),
+        }
+    }
+
+    fn sort_key(&self) -> (u8, u32) {
+        match self {
+            IndexBucket::Hangul(jamo) => (
+                0,
+                SouthKoreanOrder
+                    .jamo_weight(jamo, &JamoPosition::Initial)
+                    .unwrap_or(0),
+            ),
+            IndexBucket::Latin(c) => (1, *c as u32),
+            IndexBucket::Digit => (2, 0),
+            IndexBucket::Symbol => (3, 0),
+        }
+    }
+}
+
+impl PartialOrd for IndexBucket {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Buckets are ordered Hangul first (in South Korean dictionary order),
+/// then Latin letters, then digits, then symbols.
+impl Ord for IndexBucket {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+/// Options for grouping entries under index headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexGroupingOptions {
+    /// Whether entries starting with a double consonant are listed under its
+    /// single counterpart, so that 김 and 꽃 both fall under ㄱ.
+    pub merge_double_consonants: bool,
+}
+
+impl Default for IndexGroupingOptions {
+    fn default() -> Self {
+        IndexGroupingOptions {
+            merge_double_consonants: true,
+        }
+    }
+}
+
+/// Returns the index bucket for an entry, based on its first character
+/// after any leading whitespace. Hangul entries are bucketed by the initial
+/// consonant of their first syllable, with lone vowels treated as following
+/// a silent ㅇ.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grouping::{index_bucket, IndexBucket, IndexGroupingOptions};
+/// use hangul_cd::jamo::Jamo;
+///
+/// let mut opts = IndexGroupingOptions::default();
+/// let giyeok = Jamo::from_compatibility_jamo('ㄱ').unwrap();
+/// assert_eq!(index_bucket("꽃집", &opts), IndexBucket::Hangul(giyeok));
+///
+/// opts.merge_double_consonants = false;
+/// let ssang_giyeok = Jamo::from_compatibility_jamo('ㄲ').unwrap();
+/// assert_eq!(index_bucket("꽃집", &opts), IndexBucket::Hangul(ssang_giyeok));
+/// ```
+pub fn index_bucket(entry: &str, options: &IndexGroupingOptions) -> IndexBucket {
+    let c = match entry.trim_start().chars().next() {
+        Some(c) => c,
+        None => return IndexBucket::Symbol,
+    };
+    let initial = match HangulBlock::from_char(c) {
+        Ok(block) => Some(block.initial),
+        Err(_) => match Character::from_char(c) {
+            Ok(Character::Hangul(jamo)) => Some(jamo),
+            _ => None,
+        },
+    };
+    match initial {
+        Some(jamo) => IndexBucket::Hangul(header_jamo(jamo, options)),
+        None => match latin_base(c) {
+            Some(base) => IndexBucket::Latin(base),
+            None if c.is_numeric() => IndexBucket::Digit,
+            None => IndexBucket::Symbol,
+        },
+    }
+}
+
+/// Returns the uppercase base letter of an ASCII letter or of an accented
+/// Latin letter in the Latin-1 Supplement or Latin Extended-A blocks, with
+/// ligatures such as Æ and Œ under their first letter. Returns `None` for
+/// everything else, including letters such as Þ with no base letter from A
+/// to Z and the letter-like signs µ, ª and º.
+fn latin_base(c: char) -> Option<char> {
+    let base = match c {
+        'a'..='z' | 'A'..='Z' => c.to_ascii_uppercase(),
+        'À'..='Æ' | 'à'..='æ' | 'Ā'..='ą' => 'A',
+        'Ç' | 'ç' | 'Ć'..='č' => 'C',
+        'Ð' | 'ð' | 'Ď'..='đ' => 'D',
+        'È'..='Ë' | 'è'..='ë' | 'Ē'..='ě' => 'E',
+        'Ĝ'..='ģ' => 'G',
+        'Ĥ'..='ħ' => 'H',
+        'Ì'..='Ï' | 'ì'..='ï' | 'Ĩ'..='ĳ' => 'I',
+        'Ĵ' | 'ĵ' => 'J',
+        'Ķ'..='ĸ' => 'K',
+        'Ĺ'..='ł' => 'L',
+        'Ñ' | 'ñ' | 'Ń'..='ŋ' => 'N',
+        'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' | 'Ō'..='œ' => 'O',
+        'Ŕ'..='ř' => 'R',
+        'ß' | 'Ś'..='š' | 'ſ' => 'S',
+        'Ţ'..='ŧ' => 'T',
+        'Ù'..='Ü' | 'ù'..='ü' | 'Ũ'..='ų' => 'U',
+        'Ŵ' | 'ŵ' => 'W',
+        'Ý' | 'ý' | 'ÿ' | 'Ŷ'..='Ÿ' => 'Y',
+        'Ź'..='ž' => 'Z',
+        _ => return None,
+    };
+    Some(base)
+}
+
+fn header_jamo(jamo: Jamo, options: &IndexGroupingOptions) -> Jamo {
+    match jamo {
+        Jamo::CompositeConsonant(composite)
+            if options.merge_double_consonants || !composite.is_valid_initial() =>
+        {
+            composite.decompose().0
+        }
+        Jamo::Vowel(_) | Jamo::CompositeVowel(_) => Jamo::Consonant(JamoConsonantSingular::Ieung),
+        jamo => jamo,
+    }
+}
+
+/// A group of entries listed under one index header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexGroup<'a> {
+    /// The header the entries are listed under.
+    pub bucket: IndexBucket,
+
+    /// The entries in the group, in South Korean dictionary order.
+    pub entries: Vec<&'a str>,
+}
+
+/// Groups entries under their index headers, returning an iterator over
+/// the non-empty groups in bucket order.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grouping::{group_by_index, IndexGroupingOptions};
+///
+/// let contacts = ["박지성", "김연아", "Alice", "꽃집", "119", "강호동", "#hashtag"];
+/// let groups: Vec<(String, Vec<&str>)> =
+///     group_by_index(contacts, &IndexGroupingOptions::default())
+///         .map(|group| (group.bucket.label(), group.entries))
+///         .collect();
+/// assert_eq!(
+///     groups,
+///     vec![
+///         ("ㄱ".to_string(), vec!["강호동", "김연아", "꽃집"]),
+///         ("ㅂ".to_string(), vec!["박지성"]),
+///         ("A".to_string(), vec!["Alice"]),
+///         ("0-9".to_string(), vec!["119"]),
+///         ("#".to_string(), vec!["#hashtag"]),
+///     ]
+/// );
+/// ```
+pub fn group_by_index<'a>(
+    entries: impl IntoIterator<Item = &'a str>,
+    options: &IndexGroupingOptions,
+) -> impl Iterator<Item = IndexGroup<'a>> {
+    let mut buckets: HashMap<IndexBucket, Vec<&'a str>> = HashMap::new();
+    for entry in entries {
+        buckets
+            .entry(index_bucket(entry, options))
+            .or_default()
+            .push(entry);
+    }
+    let mut groups: Vec<IndexGroup<'a>> = buckets
+        .into_iter()
+        .map(|(bucket, mut entries)| {
+            entries.sort_by_cached_key(|entry| SouthKoreanOrder.key(entry));
+            IndexGroup { bucket, entries }
+        })
+        .collect();
+    groups.sort_by(|a, b| a.bucket.cmp(&b.bucket));
+    groups.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_for_entries() {
+        let opts = IndexGroupingOptions::default();
+        let tests = vec![
+            ("김", "ㄱ"),
+            ("  뚜벅이", "ㄷ"),
+            ("ㅃ", "ㅂ"),
+            ("ㄳ", "ㄱ"),
+            ("ㅏ", "ㅇ"),
+            ("zebra", "Z"),
+            ("Éclair", "E"),
+            ("çava", "C"),
+            ("Łódź", "L"),
+            ("Øystein", "O"),
+            ("ßtraße", "S"),
+            ("Þór", "#"),
+            ("ƒlorin", "#"),
+            ("µm", "#"),
+            ("ªb", "#"),
+            ("º C", "#"),
+            ("٣", "0-9"),
+            ("東京", "#"),
+            ("", "#"),
+            ("😀", "#"),
+        ];
+        for (entry, expected) in tests {
+            assert_eq!(index_bucket(entry, &opts).label(), expected, "{entry}");
+        }
+    }
+
+    #[test]
+    fn double_consonants_kept_separate() {
+        let opts = IndexGroupingOptions {
+            merge_double_consonants: false,
+        };
+        assert_eq!(index_bucket("뚜벅이", &opts).label(), "ㄸ");
+        assert_eq!(index_bucket("ㄳ", &opts).label(), "ㄱ");
+
+        let labels: Vec<String> = group_by_index(["쌀", "사과", "가", "까치"], &opts)
+            .map(|group| group.bucket.label())
+            .collect();
+        assert_eq!(labels, vec!["ㄱ", "ㄲ", "ㅅ", "ㅆ"]);
+    }
+
+    #[test]
+    fn accented_latin_grouped_with_base_letter() {
+        let opts = IndexGroupingOptions::default();
+        let groups: Vec<(String, Vec<&str>)> =
+            group_by_index(["Zoë", "Émile", "Eve", "Ölaf", "Þór", "Otto", "µs"], &opts)
+                .map(|group| (group.bucket.label(), group.entries))
+                .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("E".to_string(), vec!["Eve", "Émile"]),
+                ("O".to_string(), vec!["Otto", "Ölaf"]),
+                ("Z".to_string(), vec!["Zoë"]),
+                ("#".to_string(), vec!["µs", "Þór"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_input() {
+        let opts = IndexGroupingOptions::default();
+        assert_eq!(group_by_index([], &opts).count(), 0);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{block::*, jamo::*, layout::*, string::*};
+
+/// The keyboard mode a piece of text was typed in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputMode {
+    /// Hangul (한) mode, producing jamo.
+    Hangul,
+
+    /// Latin (영) mode, producing Latin letters.
+    Latin,
+}
+
+/// An estimate of whether text was typed in the wrong keyboard mode,
+/// returned by `score_wrong_mode`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrongModeScore {
+    /// The mode the text was typed in.
+    pub typed_mode: InputMode,
+
+    /// How likely the text is to have been meant for the other mode, from
+    /// 0.0 (typed as intended) to 1.0 (almost certainly the wrong mode).
+    pub score: f64,
+
+    /// The text as it would have been typed in the other mode.
+    pub converted: String,
+}
+
+/// Converts text typed in Latin mode to the Hangul it would have produced
+/// on a 2-beolsik keyboard, composing the jamo into syllables. Characters
+/// without a 2-beolsik key are kept unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::input_mode::latin_to_hangul;
+///
+/// assert_eq!(latin_to_hangul("dkssudgktpdy").unwrap(), "안녕하세요");
+/// assert_eq!(latin_to_hangul("Rhc 3ro!").unwrap(), "꽃 3개!");
+/// ```
+pub fn latin_to_hangul(s: &str) -> Result<String, StringError> {
+    let mut composer = StringComposer::new();
+    for c in s.chars() {
+        composer.push_char(key_to_jamo(c).unwrap_or(c))?;
+    }
+
+    // A trailing lone consonant is displayed as a conjoining jamo while it
+    // may still start a syllable; report it as a compatibility jamo instead
+    Ok(composer
+        .as_string()?
+        .chars()
+        .map(|c| match JamoUnicodeType::evaluate(c) {
+            JamoUnicodeType::Modern => match Character::from_char(c) {
+                Ok(Character::Hangul(jamo)) => jamo.char_compatibility(),
+                _ => c,
+            },
+            _ => c,
+        })
+        .collect())
+}
+
+/// Converts Hangul text to the keys that type it on a 2-beolsik keyboard,
+/// as it would have been typed in Latin mode. Characters that are neither
+/// syllables nor jamo are kept unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::input_mode::hangul_to_latin;
+///
+/// assert_eq!(hangul_to_latin("ㅗ디ㅣㅐ"), "hello");
+/// assert_eq!(hangul_to_latin("꽃 3개!"), "Rhc 3ro!");
+/// ```
+pub fn hangul_to_latin(s: &str) -> String {
+    s.chars()
+        .map(|c| keystrokes(c).unwrap_or_else(|| c.to_string()))
+        .collect()
+}
+
+/// Scores whether text is likely to have been typed in the wrong keyboard
+/// mode, such as "dkssudgktpdy" for 안녕하세요 or "ㅗ디ㅣㅐ" for "hello".
+/// Returns `None` if the text contains neither Latin letters nor Hangul.
+///
+/// The mode the text was typed in is taken from whichever of Latin letters
+/// or Hangul it contains more of. Latin text scores by the share of its
+/// keystrokes in words that compose entirely into complete syllables when
+/// typed in Hangul mode, since English words rarely alternate consonants and
+/// vowels the way Korean syllables do. Hangul text scores by the share of
+/// its characters in words containing lone jamo; repeated lone jamo such as
+/// ㅋㅋ or ㅠㅠ are treated as intended. Short or mixed text can score near
+/// the middle, so callers should pick a threshold suited to their input.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::input_mode::{score_wrong_mode, InputMode};
+///
+/// let guess = score_wrong_mode("dkssudgktpdy").unwrap();
+/// assert_eq!(guess.typed_mode, InputMode::Latin);
+/// assert_eq!(guess.score, 1.0);
+/// assert_eq!(guess.converted, "안녕하세요");
+///
+/// let guess = score_wrong_mode("ㅗ디ㅣㅐ").unwrap();
+/// assert_eq!(guess.typed_mode, InputMode::Hangul);
+/// assert_eq!(guess.converted, "hello");
+/// assert!(guess.score > 0.5);
+///
+/// assert!(score_wrong_mode("hello").unwrap().score < 0.5);
+/// assert_eq!(score_wrong_mode("안녕하세요 ㅋㅋㅋ").unwrap().score, 0.0);
+/// assert_eq!(score_wrong_mode("123"), None);
+/// ```
+pub fn score_wrong_mode(s: &str) -> Option<WrongModeScore> {
+    let latin = s.chars().filter(|c| key_to_jamo(*c).is_some()).count();
+    let hangul = s.chars().filter(|c| keystrokes(*c).is_some()).count();
+    if latin == 0 && hangul == 0 {
+        return None;
+    }
+
+    if latin > hangul {
+        let converted = latin_to_hangul(s).ok()?;
+        let (mut korean, mut total) = (0, 0);
+        for word in converted.split_whitespace() {
+            let count = word
+                .chars()
+                .filter_map(keystrokes)
+                .map(|k| k.len())
+                .sum::<usize>();
+            if word
+                .chars()
+                .all(|c| keystrokes(c).is_none() || HangulBlock::from_char(c).is_ok())
+            {
+                korean += count;
+            }
+            total += count;
+        }
+        Some(WrongModeScore {
+            typed_mode: InputMode::Latin,
+            score: ratio(korean, total),
+            converted,
+        })
+    } else {
+        let (mut wrong, mut total) = (0, 0);
+        for word in s.split_whitespace() {
+            let chars: Vec<char> = word.chars().collect();
+            let count = chars.iter().filter(|c| keystrokes(**c).is_some()).count();
+            let has_lone_jamo = chars.iter().enumerate().any(|(i, c)| {
+                let repeated = (i > 0 && chars[i - 1] == *c) || chars.get(i + 1) == Some(c);
+                keystrokes(*c).is_some() && HangulBlock::from_char(*c).is_err() && !repeated
+            });
+            if has_lone_jamo {
+                wrong += count;
+            }
+            total += count;
+        }
+        Some(WrongModeScore {
+            typed_mode: InputMode::Hangul,
+            score: ratio(wrong, total),
+            converted: hangul_to_latin(s),
+        })
+    }
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+fn key_to_jamo(c: char) -> Option<char> {
+    match Dubeolsik.map_char(c) {
+        KeyOutput::Jamo(jamo) | KeyOutput::PositionedJamo(jamo, _) => {
+            Some(jamo.char_compatibility())
+        }
+        KeyOutput::Passthrough(_) => None,
+    }
+}
+
+/// Returns the 2-beolsik keys that type a syllable or jamo.
+fn keystrokes(c: char) -> Option<String> {
+    let is_jamo = matches!(Character::from_char(c), Ok(Character::Hangul(_)));
+    if !is_jamo && HangulBlock::from_char(c).is_err() {
+        return None;
+    }
+    text_to_keystrokes(&c.to_string(), &Dubeolsik).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_conversion() {
+        let tests = vec![
+            ("dkssudgktpdy", "안녕하세요"),
+            ("rkqt", "값"),
+            ("dhksfy", "완료"),
+            ("akfTma", "말씀"),
+            ("dmlwk", "의자"),
+            ("rkR", "갂"),
+            ("hello, world", "ㅗ디ㅣㅐ, 재깅"),
+        ];
+        for (latin, hangul) in tests {
+            assert_eq!(latin_to_hangul(latin).unwrap(), hangul, "{latin}");
+            assert_eq!(hangul_to_latin(hangul), latin, "{hangul}");
+        }
+    }
+
+    #[test]
+    fn uppercase_keys() {
+        assert_eq!(latin_to_hangul("DKSSUD").unwrap(), "안녕");
+        assert_eq!(latin_to_hangul("EK").unwrap(), "따");
+        assert_eq!(latin_to_hangul("keyboard").unwrap(), "ㅏ됴ㅠㅐㅁㄱㅇ");
+    }
+
+    #[test]
+    fn wrong_mode_scores() {
+        let likely = [
+            "tkfkdgo",
+            "rhdqn",
+            "ghkdlxld",
+            "ㅗ디ㅣㅐ 재깅",
+            "ㅏ됴ㅠㅐㅁㄱㅇ",
+        ];
+        for text in likely {
+            let score = score_wrong_mode(text).unwrap().score;
+            assert!(score >= 0.5, "{text}: {score}");
+        }
+        let unlikely = [
+            "hello",
+            "keyboard",
+            "thank you",
+            "good morning",
+            "사랑해",
+            "ㅠㅠ 배고파",
+            "ㅎㅎ",
+        ];
+        for text in unlikely {
+            let score = score_wrong_mode(text).unwrap().score;
+            assert!(score < 0.5, "{text}: {score}");
+        }
+    }
+}
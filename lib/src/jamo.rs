@@ -61,6 +61,7 @@ pub(crate) const S_BASE: u32 = 0xAC00;
 pub(crate) const L_BASE: u32 = 0x1100;
 pub(crate) const V_BASE: u32 = 0x1161;
 pub(crate) const T_BASE: u32 = 0x11A7;
+pub(crate) const L_COUNT: u32 = 19;
 pub(crate) const V_COUNT: u32 = 21;
 pub(crate) const T_COUNT: u32 = 28;
 pub(crate) const N_COUNT: u32 = V_COUNT * T_COUNT;
@@ -393,7 +394,7 @@ impl Character {
 
 /// An enum representing the different types of Hangul Jamo characters:
 /// consonants, composite consonants, vowels, and composite vowels.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum Jamo {
     Consonant(JamoConsonantSingular),
     CompositeConsonant(JamoConsonantComposite),
@@ -402,7 +403,7 @@ pub enum Jamo {
 }
 
 /// An enum representing singular Hangul consonant jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum JamoConsonantSingular {
     /// ㄱ
     Giyeok,
@@ -655,7 +656,7 @@ impl JamoConsonantSingular {
 }
 
 /// An enum representing composite Hangul consonant jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum JamoConsonantComposite {
     /// ㄳ
     GiyeokSiot,
@@ -922,7 +923,7 @@ impl JamoConsonantComposite {
 }
 
 /// An enum representing singular Hangul vowel jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum JamoVowelSingular {
     /// ㅏ
     A,
@@ -1051,7 +1052,7 @@ impl JamoVowelSingular {
 }
 
 /// An enum representing composite Hangul vowel jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum JamoVowelComposite {
     /// ㅘ
     Wa,
@@ -0,0 +1,952 @@
+use thiserror::Error;
+
+use crate::{block::*, jamo::*};
+
+use LayoutKey::{Char, Final, Initial, Vowel};
+
+/// An error type for keyboard layouts.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum LayoutError {
+    /// A key types a jamo that the composer cannot place in the position
+    /// the layout gives it, such as ㄸ as a final consonant. The key is
+    /// given as the character it types on a US QWERTY keyboard.
+    #[error("Key '{0}' types '{1}', which cannot be placed in its position in a syllable")]
+    UnplaceableJamo(char, char),
+
+    /// A character cannot be typed on the layout.
+    #[error("Character '{0}' cannot be typed on this layout")]
+    UntypeableCharacter(char),
+
+    /// Occurs when there is an error related to syllable blocks.
+    #[error("Block error: {0}")]
+    BlockError(#[from] BlockError),
+
+    /// A line of a layout definition is not a name, a comment, or a key
+    /// followed by one or two outputs.
+    #[error("Line {0}: expected a key followed by one or two outputs, found '{1}'")]
+    InvalidLine(usize, String),
+
+    /// A layout definition names a key that is not on a US QWERTY keyboard.
+    #[error("Line {0}: '{1}' is not a key on a US QWERTY keyboard")]
+    UnknownKey(usize, String),
+
+    /// A layout definition gives an output that is not a known jamo, such
+    /// as an archaic jamo or a position without a jamo.
+    #[error("Line {0}: '{1}' is not a known jamo")]
+    UnknownJamo(usize, String),
+
+    /// A layout definition maps a key to a jamo that the composer cannot
+    /// place in its position, as `UnplaceableJamo` but with the line the
+    /// key is mapped on.
+    #[error(
+        "Line {0}: key '{1}' types '{2}', which cannot be placed in its position in a syllable"
+    )]
+    UnplaceableKey(usize, char, char),
+
+    /// A layout definition maps the same key twice; the line it was first
+    /// mapped on is given last.
+    #[error("Line {0}: key '{1}' is already mapped on line {2}")]
+    DuplicateKey(usize, char, usize),
+}
+
+/// The result of pressing a key on a Korean keyboard layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyOutput {
+    /// The key types a jamo, to be passed on to a composer, which decides
+    /// its position within the syllable.
+    Jamo(Jamo),
+
+    /// The key types a jamo for a fixed position within the syllable, as on
+    /// 3-beolsik layouts with separate initial and final consonant keys.
+    PositionedJamo(Jamo, JamoPosition),
+
+    /// The key is not part of the layout and types the given character
+    /// unchanged, as it would on a US QWERTY keyboard.
+    Passthrough(char),
+}
+
+/// A Korean keyboard layout, mapping physical keys to the jamo they type.
+///
+/// Keys are named by the character they type on a US QWERTY keyboard
+/// without Shift, such as `'r'` or `'1'`, and the Shift state is given
+/// separately. `map_char` accepts the typed character directly, for front
+/// ends that only see the shifted result.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::jamo::Jamo;
+/// use hangul_cd::layout::{Dubeolsik, KeyOutput, KeyboardLayout};
+///
+/// let layout = Dubeolsik;
+/// let giyeok = Jamo::from_compatibility_jamo('ㄱ').unwrap();
+/// let ssang_giyeok = Jamo::from_compatibility_jamo('ㄲ').unwrap();
+///
+/// assert_eq!(layout.map_key('r', false), KeyOutput::Jamo(giyeok));
+/// assert_eq!(layout.map_key('r', true), KeyOutput::Jamo(ssang_giyeok.clone()));
+/// assert_eq!(layout.map_char('R'), KeyOutput::Jamo(ssang_giyeok));
+/// assert_eq!(layout.map_key('1', true), KeyOutput::Passthrough('!'));
+/// ```
+pub trait KeyboardLayout {
+    /// Returns the name of the layout.
+    fn name(&self) -> &str;
+
+    /// Returns the output of a key, named by its unshifted US QWERTY
+    /// character, pressed with or without Shift.
+    fn map_key(&self, key: char, shift: bool) -> KeyOutput;
+
+    /// Returns the output of the key that types the given character on a
+    /// US QWERTY keyboard, with Shift inferred from the character.
+    fn map_char(&self, c: char) -> KeyOutput {
+        let (key, shift) = unshifted(c);
+        self.map_key(key, shift)
+    }
+}
+
+/// The shifted and unshifted characters of the US QWERTY keys whose
+/// shifted character is not simply an uppercase letter.
+const US_QWERTY_SYMBOLS: [(char, char); 21] = [
+    ('`', '~'),
+    ('1', '!'),
+    ('2', '@'),
+    ('3', '#'),
+    ('4', '$'),
+    ('5', '%'),
+    ('6', '^'),
+    ('7', '&'),
+    ('8', '*'),
+    ('9', '('),
+    ('0', ')'),
+    ('-', '_'),
+    ('=', '+'),
+    ('[', '{'),
+    (']', '}'),
+    ('\\', '|'),
+    (';', ':'),
+    ('\'', '"'),
+    (',', '<'),
+    ('.', '>'),
+    ('/', '?'),
+];
+
+/// Returns the key and Shift state that type a character on a US QWERTY
+/// keyboard. Characters not on the keyboard are returned unshifted.
+pub(crate) fn unshifted(c: char) -> (char, bool) {
+    if c.is_ascii_uppercase() {
+        return (c.to_ascii_lowercase(), true);
+    }
+    match US_QWERTY_SYMBOLS.iter().find(|(_, shifted)| *shifted == c) {
+        Some((key, _)) => (*key, true),
+        None => (c, false),
+    }
+}
+
+/// Returns the character a key types on a US QWERTY keyboard with the
+/// given Shift state.
+pub(crate) fn shifted(key: char, shift: bool) -> char {
+    if !shift {
+        return key;
+    }
+    if key.is_ascii_lowercase() {
+        return key.to_ascii_uppercase();
+    }
+    match US_QWERTY_SYMBOLS.iter().find(|(k, _)| *k == key) {
+        Some((_, shifted)) => *shifted,
+        None => key,
+    }
+}
+
+/// The letter keys of the standard 2-beolsik layout, with the jamo each
+/// types without and with Shift.
+const DUBEOLSIK: [(char, char, char); 26] = [
+    ('q', 'ㅂ', 'ㅃ'),
+    ('w', 'ㅈ', 'ㅉ'),
+    ('e', 'ㄷ', 'ㄸ'),
+    ('r', 'ㄱ', 'ㄲ'),
+    ('t', 'ㅅ', 'ㅆ'),
+    ('y', 'ㅛ', 'ㅛ'),
+    ('u', 'ㅕ', 'ㅕ'),
+    ('i', 'ㅑ', 'ㅑ'),
+    ('o', 'ㅐ', 'ㅒ'),
+    ('p', 'ㅔ', 'ㅖ'),
+    ('a', 'ㅁ', 'ㅁ'),
+    ('s', 'ㄴ', 'ㄴ'),
+    ('d', 'ㅇ', 'ㅇ'),
+    ('f', 'ㄹ', 'ㄹ'),
+    ('g', 'ㅎ', 'ㅎ'),
+    ('h', 'ㅗ', 'ㅗ'),
+    ('j', 'ㅓ', 'ㅓ'),
+    ('k', 'ㅏ', 'ㅏ'),
+    ('l', 'ㅣ', 'ㅣ'),
+    ('z', 'ㅋ', 'ㅋ'),
+    ('x', 'ㅌ', 'ㅌ'),
+    ('c', 'ㅊ', 'ㅊ'),
+    ('v', 'ㅍ', 'ㅍ'),
+    ('b', 'ㅠ', 'ㅠ'),
+    ('n', 'ㅜ', 'ㅜ'),
+    ('m', 'ㅡ', 'ㅡ'),
+];
+
+/// The standard 2-beolsik (두벌식) layout of KS X 5002, with consonants on
+/// the left hand and vowels on the right. Shift types the double
+/// consonants ㄲ, ㄸ, ㅃ, ㅆ and ㅉ and the vowels ㅒ and ㅖ; other shifted
+/// letters type the same jamo as without Shift. All other keys pass
+/// through unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Dubeolsik;
+
+impl KeyboardLayout for Dubeolsik {
+    fn name(&self) -> &str {
+        "Dubeolsik (KS X 5002)"
+    }
+
+    fn map_key(&self, key: char, shift: bool) -> KeyOutput {
+        let jamo = DUBEOLSIK
+            .iter()
+            .find(|(k, _, _)| *k == key)
+            .map(|(_, plain, shifted)| if shift { *shifted } else { *plain });
+        match jamo.and_then(|c| Jamo::from_compatibility_jamo(c).ok()) {
+            Some(jamo) => KeyOutput::Jamo(jamo),
+            None => KeyOutput::Passthrough(shifted(key, shift)),
+        }
+    }
+}
+
+/// An entry in a layout table: the jamo and position a key types, or a
+/// character it types unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LayoutKey {
+    Initial(char),
+    Vowel(char),
+    Final(char),
+    Jamo(char),
+    Char(char),
+}
+
+impl LayoutKey {
+    fn output(&self) -> KeyOutput {
+        let (c, position) = match self {
+            LayoutKey::Initial(c) => (*c, JamoPosition::Initial),
+            LayoutKey::Vowel(c) => (*c, JamoPosition::Vowel),
+            LayoutKey::Final(c) => (*c, JamoPosition::Final),
+            LayoutKey::Jamo(c) => {
+                return match Jamo::from_compatibility_jamo(*c) {
+                    Ok(jamo) => KeyOutput::Jamo(jamo),
+                    Err(_) => KeyOutput::Passthrough(*c),
+                };
+            }
+            LayoutKey::Char(c) => return KeyOutput::Passthrough(*c),
+        };
+        match Jamo::from_compatibility_jamo(c) {
+            Ok(jamo) => KeyOutput::PositionedJamo(jamo, position),
+            Err(_) => KeyOutput::Passthrough(c),
+        }
+    }
+}
+
+/// Looks up a key in a layout table of keys with their unshifted and
+/// shifted entries. Keys missing from the table pass through unchanged.
+fn map_table_key(table: &[(char, LayoutKey, LayoutKey)], key: char, shift: bool) -> KeyOutput {
+    match table.iter().find(|(k, _, _)| *k == key) {
+        Some((_, plain, shifted)) => {
+            if shift {
+                shifted.output()
+            } else {
+                plain.output()
+            }
+        }
+        None => KeyOutput::Passthrough(shifted(key, shift)),
+    }
+}
+
+const SEBEOLSIK_390: [(char, LayoutKey, LayoutKey); 39] = [
+    ('1', Final('ㅎ'), Final('ㄲ')),
+    ('2', Final('ㅆ'), Final('ㄺ')),
+    ('3', Final('ㅂ'), Final('ㅈ')),
+    ('4', Vowel('ㅛ'), Final('ㄿ')),
+    ('5', Vowel('ㅠ'), Final('ㄾ')),
+    ('6', Vowel('ㅑ'), Char('^')),
+    ('7', Vowel('ㅖ'), Char('&')),
+    ('8', Vowel('ㅢ'), Char('*')),
+    ('9', Vowel('ㅜ'), Char('(')),
+    ('0', Initial('ㅋ'), Char(')')),
+    ('q', Final('ㅅ'), Final('ㅍ')),
+    ('w', Final('ㄹ'), Final('ㅌ')),
+    ('e', Vowel('ㅕ'), Final('ㄵ')),
+    ('r', Vowel('ㅐ'), Final('ㅀ')),
+    ('t', Vowel('ㅓ'), Final('ㄽ')),
+    ('y', Initial('ㄹ'), Char('5')),
+    ('u', Initial('ㄷ'), Char('6')),
+    ('i', Initial('ㅁ'), Char('7')),
+    ('o', Initial('ㅊ'), Char('8')),
+    ('p', Initial('ㅍ'), Char('9')),
+    ('a', Final('ㅇ'), Final('ㄷ')),
+    ('s', Final('ㄴ'), Final('ㄶ')),
+    ('d', Vowel('ㅣ'), Final('ㄼ')),
+    ('f', Vowel('ㅏ'), Final('ㄻ')),
+    ('g', Vowel('ㅡ'), Vowel('ㅒ')),
+    ('h', Initial('ㄴ'), Char('0')),
+    ('j', Initial('ㅇ'), Char('1')),
+    ('k', Initial('ㄱ'), Char('2')),
+    ('l', Initial('ㅈ'), Char('3')),
+    (';', Initial('ㅂ'), Char('4')),
+    ('\'', Initial('ㅌ'), Char('"')),
+    ('z', Final('ㅁ'), Final('ㅊ')),
+    ('x', Final('ㄱ'), Final('ㅄ')),
+    ('c', Vowel('ㅔ'), Final('ㅋ')),
+    ('v', Vowel('ㅗ'), Final('ㄳ')),
+    ('b', Vowel('ㅜ'), Char('?')),
+    ('n', Initial('ㅅ'), Char('-')),
+    ('m', Initial('ㅎ'), Char('"')),
+    ('/', Vowel('ㅗ'), Char('!')),
+];
+
+/// The 3-beolsik (세벌식) 390 layout, with initial consonants on the right
+/// hand, vowels in the middle and final consonants on the left. Every key
+/// types a jamo for a fixed position, so its output is meant for
+/// `StringComposer::push_positioned`.
+///
+/// Double initials are typed by pressing an initial twice, and composite
+/// vowels by combining ㅗ or ㅜ with a following vowel; the extra ㅗ and ㅜ
+/// keys on `/` and `9` make these combinations easier to reach. Digits are
+/// typed with Shift on the right-hand initial keys.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::layout::{KeyOutput, KeyboardLayout, Sebeolsik390};
+/// use hangul_cd::string::StringComposer;
+///
+/// let mut composer = StringComposer::new();
+/// for key in "kfxjdjfs".chars() {
+///     match Sebeolsik390.map_char(key) {
+///         KeyOutput::PositionedJamo(jamo, position) => {
+///             composer.push_positioned(&jamo, position).unwrap()
+///         }
+///         KeyOutput::Jamo(jamo) => composer.push_char(jamo.char_compatibility()).unwrap(),
+///         KeyOutput::Passthrough(c) => composer.push_char(c).unwrap(),
+///     }
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "각이안");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sebeolsik390;
+
+impl KeyboardLayout for Sebeolsik390 {
+    fn name(&self) -> &str {
+        "Sebeolsik 390"
+    }
+
+    fn map_key(&self, key: char, shift: bool) -> KeyOutput {
+        map_table_key(&SEBEOLSIK_390, key, shift)
+    }
+}
+
+const SEBEOLSIK_FINAL: [(char, LayoutKey, LayoutKey); 44] = [
+    ('1', Final('ㅎ'), Final('ㄲ')),
+    ('2', Final('ㅆ'), Final('ㄺ')),
+    ('3', Final('ㅂ'), Final('ㅈ')),
+    ('4', Vowel('ㅛ'), Final('ㄿ')),
+    ('5', Vowel('ㅠ'), Final('ㄾ')),
+    ('6', Vowel('ㅑ'), Char('=')),
+    ('7', Vowel('ㅖ'), Char('“')),
+    ('8', Vowel('ㅢ'), Char('”')),
+    ('9', Vowel('ㅜ'), Char('\'')),
+    ('0', Initial('ㅋ'), Char('~')),
+    ('-', Char(')'), Char(';')),
+    ('=', Char('>'), Char('+')),
+    ('q', Final('ㅅ'), Final('ㅍ')),
+    ('w', Final('ㄹ'), Final('ㅌ')),
+    ('e', Vowel('ㅕ'), Final('ㄵ')),
+    ('r', Vowel('ㅐ'), Final('ㅀ')),
+    ('t', Vowel('ㅓ'), Final('ㄽ')),
+    ('y', Initial('ㄹ'), Char('5')),
+    ('u', Initial('ㄷ'), Char('6')),
+    ('i', Initial('ㅁ'), Char('7')),
+    ('o', Initial('ㅊ'), Char('8')),
+    ('p', Initial('ㅍ'), Char('9')),
+    ('[', Char('('), Char('%')),
+    (']', Char('<'), Char('/')),
+    ('\\', Char(':'), Char('\\')),
+    ('a', Final('ㅇ'), Final('ㄷ')),
+    ('s', Final('ㄴ'), Final('ㄶ')),
+    ('d', Vowel('ㅣ'), Final('ㄼ')),
+    ('f', Vowel('ㅏ'), Final('ㄻ')),
+    ('g', Vowel('ㅡ'), Vowel('ㅒ')),
+    ('h', Initial('ㄴ'), Char('0')),
+    ('j', Initial('ㅇ'), Char('1')),
+    ('k', Initial('ㄱ'), Char('2')),
+    ('l', Initial('ㅈ'), Char('3')),
+    (';', Initial('ㅂ'), Char('4')),
+    ('\'', Initial('ㅌ'), Char('·')),
+    ('z', Final('ㅁ'), Final('ㅊ')),
+    ('x', Final('ㄱ'), Final('ㅄ')),
+    ('c', Vowel('ㅔ'), Final('ㅋ')),
+    ('v', Vowel('ㅗ'), Final('ㄳ')),
+    ('b', Vowel('ㅜ'), Char('?')),
+    ('n', Initial('ㅅ'), Char('-')),
+    ('m', Initial('ㅎ'), Char('"')),
+    ('/', Vowel('ㅗ'), Char('!')),
+];
+
+/// The Sebeolsik Final (세벌식 최종, 391) layout. Its jamo keys match
+/// `Sebeolsik390`, including the direct Shift keys for composite finals
+/// such as ㄳ and ㄺ and the direct ㅢ key, but its symbol layer differs:
+/// curly quotes, `·` and `~` are on Shift, and brackets and comparison
+/// signs are on the unshifted `-`, `=`, `[`, `]` and `\` keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SebeolsikFinal;
+
+impl KeyboardLayout for SebeolsikFinal {
+    fn name(&self) -> &str {
+        "Sebeolsik Final"
+    }
+
+    fn map_key(&self, key: char, shift: bool) -> KeyOutput {
+        map_table_key(&SEBEOLSIK_FINAL, key, shift)
+    }
+}
+
+/// A keyboard layout loaded at runtime from a plain-text definition, for
+/// layouts not built into the crate.
+///
+/// Each line of a definition is blank, a comment starting with `#`, the
+/// layout's name as `name = ...`, or a key followed by what it types
+/// without Shift and, optionally, with Shift. Keys are named by their
+/// unshifted US QWERTY character, or `space` for the space bar. Outputs
+/// are written as:
+///
+/// - a compatibility jamo such as `ㄱ`, placed by the composer as on
+///   2-beolsik layouts;
+/// - `initial:ㄱ`, `vowel:ㅏ` or `final:ㄱ`, typed for a fixed position as
+///   on 3-beolsik layouts;
+/// - any other single character, typed unchanged.
+///
+/// Keys missing from the definition, and the Shift layer of keys given a
+/// single output, type their US QWERTY characters unchanged. Definitions
+/// are checked with `validate_layout` once parsed, and keys that type a jamo
+/// the composer cannot place are reported with their line.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::{Jamo, JamoPosition};
+/// use hangul_cd::layout::{CustomLayout, KeyOutput, KeyboardLayout, LayoutError};
+///
+/// let layout = CustomLayout::parse(
+///     "# A few keys of an in-house layout
+///      name = Team Layout
+///      r  ㄱ          ㄲ
+///      k  ㅏ
+///      1  final:ㅎ    !
+///      6  vowel:ㅑ    ^",
+/// )
+/// .unwrap();
+/// let jamo = |c| Jamo::from_compatibility_jamo(c).unwrap();
+///
+/// assert_eq!(layout.name(), "Team Layout");
+/// assert_eq!(layout.map_char('R'), KeyOutput::Jamo(jamo('ㄲ')));
+/// assert_eq!(
+///     layout.map_char('1'),
+///     KeyOutput::PositionedJamo(jamo('ㅎ'), JamoPosition::Final)
+/// );
+/// assert_eq!(layout.map_char('K'), KeyOutput::Passthrough('K'));
+///
+/// assert_eq!(
+///     CustomLayout::parse("r ㄱ\nr ㅅ"),
+///     Err(LayoutError::DuplicateKey(2, 'r', 1))
+/// );
+/// assert_eq!(
+///     CustomLayout::parse("q final:ㅿ"),
+///     Err(LayoutError::UnknownJamo(1, "final:ㅿ".to_string()))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomLayout {
+    name: String,
+    keys: Vec<(char, LayoutKey, LayoutKey)>,
+}
+
+impl CustomLayout {
+    /// Parses a layout from its plain-text definition. Returns an error
+    /// with the line number if a line cannot be read, names an unknown key
+    /// or jamo, maps a key that an earlier line already mapped, or maps a
+    /// key to a jamo that `validate_layout` finds cannot be placed.
+    pub fn parse(definition: &str) -> Result<Self, LayoutError> {
+        let mut layout = CustomLayout {
+            name: "Custom layout".to_string(),
+            keys: Vec::new(),
+        };
+        let mut key_lines = Vec::new();
+        for (index, line) in definition.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(rest) = line.strip_prefix("name")
+                && let Some(name) = rest.trim_start().strip_prefix('=')
+            {
+                layout.name = name.trim().to_string();
+                continue;
+            }
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let (key, plain, shift) = match tokens.as_slice() {
+                [key, plain] => (*key, *plain, None),
+                [key, plain, shift] => (*key, *plain, Some(*shift)),
+                _ => return Err(LayoutError::InvalidLine(line_number, line.to_string())),
+            };
+            let key = parse_key(key, line_number)?;
+            if let Some((_, first_line)) = key_lines.iter().find(|(k, _)| *k == key) {
+                return Err(LayoutError::DuplicateKey(line_number, key, *first_line));
+            }
+            key_lines.push((key, line_number));
+
+            let plain = parse_output(plain, line_number)?;
+            let shift = match shift {
+                Some(shift) => parse_output(shift, line_number)?,
+                None => Char(shifted(key, true)),
+            };
+            layout.keys.push((key, plain, shift));
+        }
+        match validate_layout(&layout) {
+            Err(LayoutError::UnplaceableJamo(typed, jamo)) => {
+                match key_lines
+                    .iter()
+                    .find(|(key, _)| shifted(*key, false) == typed || shifted(*key, true) == typed)
+                {
+                    Some((_, line_number)) => {
+                        Err(LayoutError::UnplaceableKey(*line_number, typed, jamo))
+                    }
+                    None => Err(LayoutError::UnplaceableJamo(typed, jamo)),
+                }
+            }
+            result => result.map(|_| layout),
+        }
+    }
+}
+
+impl KeyboardLayout for CustomLayout {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn map_key(&self, key: char, shift: bool) -> KeyOutput {
+        map_table_key(&self.keys, key, shift)
+    }
+}
+
+/// Reads a key name from a layout definition.
+fn parse_key(token: &str, line_number: usize) -> Result<char, LayoutError> {
+    let mut chars = token.chars();
+    let key = match (chars.next(), chars.next()) {
+        _ if token == "space" => Some(' '),
+        (Some(c), None) => us_qwerty_keys().find(|key| *key == c),
+        _ => None,
+    };
+    key.ok_or_else(|| LayoutError::UnknownKey(line_number, token.to_string()))
+}
+
+/// Reads a key output from a layout definition.
+fn parse_output(token: &str, line_number: usize) -> Result<LayoutKey, LayoutError> {
+    let unknown_jamo = || LayoutError::UnknownJamo(line_number, token.to_string());
+    let (position, rest) = match token.split_once(':') {
+        Some((position, rest)) if !rest.is_empty() => (Some(position), rest),
+        _ => (None, token),
+    };
+
+    let mut chars = rest.chars();
+    let c = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => return Err(unknown_jamo()),
+    };
+    let jamo = match JamoUnicodeType::evaluate(c) {
+        JamoUnicodeType::NonHangul => None,
+        _ => match Character::from_char(c) {
+            Ok(Character::Hangul(jamo)) => Some(jamo.char_compatibility()),
+            _ => return Err(unknown_jamo()),
+        },
+    };
+
+    match (position, jamo) {
+        (None, Some(jamo)) => Ok(LayoutKey::Jamo(jamo)),
+        (None, None) => Ok(Char(c)),
+        (Some("initial"), Some(jamo)) => Ok(Initial(jamo)),
+        (Some("vowel"), Some(jamo)) => Ok(Vowel(jamo)),
+        (Some("final"), Some(jamo)) => Ok(Final(jamo)),
+        (Some(_), _) => Err(unknown_jamo()),
+    }
+}
+
+/// Returns every key of a US QWERTY keyboard, named by its unshifted
+/// character.
+fn us_qwerty_keys() -> impl Iterator<Item = char> {
+    ('a'..='z')
+        .chain(US_QWERTY_SYMBOLS.iter().map(|(key, _)| *key))
+        .chain([' '])
+}
+
+/// Checks that every key of a layout, with and without Shift, types either
+/// a character or a jamo that the composer can place in the layout's
+/// position for it. A positioned initial must be able to start a syllable,
+/// a vowel must follow an initial, and a final must follow an initial and
+/// vowel.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::layout::{validate_layout, Dubeolsik, SebeolsikFinal};
+///
+/// assert_eq!(validate_layout(&Dubeolsik), Ok(()));
+/// assert_eq!(validate_layout(&SebeolsikFinal), Ok(()));
+/// ```
+pub fn validate_layout(layout: &impl KeyboardLayout) -> Result<(), LayoutError> {
+    for key in us_qwerty_keys() {
+        for shift in [false, true] {
+            let placeable = match layout.map_key(key, shift) {
+                KeyOutput::Passthrough(_) => continue,
+                KeyOutput::PositionedJamo(jamo, position) => {
+                    let mut composer = composer_expecting(&position);
+                    composer.push_positioned(&jamo, position) == BlockPushResult::Success
+                }
+                KeyOutput::Jamo(jamo) => [
+                    JamoPosition::Initial,
+                    JamoPosition::Vowel,
+                    JamoPosition::Final,
+                ]
+                .iter()
+                .any(|position| {
+                    composer_expecting(position).push(&jamo) == BlockPushResult::Success
+                }),
+            };
+            if !placeable {
+                let jamo = match layout.map_key(key, shift) {
+                    KeyOutput::Jamo(jamo) | KeyOutput::PositionedJamo(jamo, _) => {
+                        jamo.char_compatibility()
+                    }
+                    KeyOutput::Passthrough(c) => c,
+                };
+                return Err(LayoutError::UnplaceableJamo(shifted(key, shift), jamo));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the keys to press on a layout to type the given text, as the
+/// characters they type on a US QWERTY keyboard. Each syllable is split into
+/// its initial, vowel and final with `HangulBlock::decomposed_tuple`, and
+/// double or composite jamo without a key of their own are typed as their
+/// parts, so ㅘ is typed as ㅗ then ㅏ and ㄳ as ㄱ then ㅅ on 2-beolsik.
+/// Unshifted keys are preferred when a jamo can be typed either way.
+///
+/// Returns an error if a character cannot be typed on the layout.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::layout::{text_to_keystrokes, Dubeolsik, Sebeolsik390};
+///
+/// assert_eq!(text_to_keystrokes("안녕", &Dubeolsik).unwrap(), "dkssud");
+/// assert_eq!(text_to_keystrokes("왔고 넋!", &Dubeolsik).unwrap(), "dhkTrh sjrt!");
+/// assert_eq!(text_to_keystrokes("꽃", &Sebeolsik390).unwrap(), "kkvZ");
+/// ```
+pub fn text_to_keystrokes(text: &str, layout: &impl KeyboardLayout) -> Result<String, LayoutError> {
+    let mut result = String::new();
+    for c in text.chars() {
+        if let Ok(block) = HangulBlock::from_char(c) {
+            let (i1, i2, v1, v2, f1, f2) = block.decomposed_tuple()?;
+            let mut slots = vec![
+                (block.initial, JamoPosition::Initial, [i1, i2]),
+                (block.vowel, JamoPosition::Vowel, [v1, v2]),
+            ];
+            if let Some(final_jamo) = block.final_optional {
+                slots.push((final_jamo, JamoPosition::Final, [f1, f2]));
+            }
+            for (jamo, position, parts) in slots {
+                let keys = match jamo_key(layout, &jamo, &position) {
+                    Some(key) => vec![key],
+                    None => parts
+                        .iter()
+                        .flatten()
+                        .map(|part| jamo_key(layout, part, &position))
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or(LayoutError::UntypeableCharacter(c))?,
+                };
+                result.extend(keys);
+            }
+            continue;
+        }
+
+        let keys = match Character::from_char(c) {
+            Ok(Character::Hangul(jamo)) => lone_jamo_key(layout, &jamo),
+            _ => find_key(layout, |output| *output == KeyOutput::Passthrough(c)).map(String::from),
+        };
+        result.push_str(&keys.ok_or(LayoutError::UntypeableCharacter(c))?);
+    }
+    Ok(result)
+}
+
+/// Returns the first key, unshifted keys first, whose output satisfies the
+/// predicate, as the character it types on a US QWERTY keyboard.
+fn find_key(layout: &impl KeyboardLayout, is_match: impl Fn(&KeyOutput) -> bool) -> Option<char> {
+    [false, true].into_iter().find_map(|shift| {
+        us_qwerty_keys()
+            .find(|key| is_match(&layout.map_key(*key, shift)))
+            .map(|key| shifted(key, shift))
+    })
+}
+
+/// Returns the key typing a jamo in the given position, if there is one.
+fn jamo_key(layout: &impl KeyboardLayout, jamo: &Jamo, position: &JamoPosition) -> Option<char> {
+    find_key(layout, |output| match output {
+        KeyOutput::Jamo(j) => j == jamo,
+        KeyOutput::PositionedJamo(j, p) => j == jamo && p == position,
+        KeyOutput::Passthrough(_) => false,
+    })
+}
+
+/// Returns the keys typing a jamo on its own, outside a syllable. Consonants
+/// are typed as initials where possible, and as finals otherwise.
+fn lone_jamo_key(layout: &impl KeyboardLayout, jamo: &Jamo) -> Option<String> {
+    let positions: &[JamoPosition] = match jamo {
+        Jamo::Consonant(_) | Jamo::CompositeConsonant(_) => {
+            &[JamoPosition::Initial, JamoPosition::Final]
+        }
+        Jamo::Vowel(_) | Jamo::CompositeVowel(_) => &[JamoPosition::Vowel],
+    };
+    positions.iter().find_map(|position| {
+        if let Some(key) = jamo_key(layout, jamo, position) {
+            return Some(key.to_string());
+        }
+        let (a, b) = match jamo {
+            Jamo::CompositeConsonant(c) => c.decompose(),
+            Jamo::CompositeVowel(c) => c.decompose(),
+            _ => return None,
+        };
+        Some(format!(
+            "{}{}",
+            jamo_key(layout, &a, position)?,
+            jamo_key(layout, &b, position)?
+        ))
+    })
+}
+
+/// Returns a composer holding the jamo that come before the given position
+/// in a syllable.
+fn composer_expecting(position: &JamoPosition) -> BlockComposer {
+    let mut composer = BlockComposer::new();
+    let before: &[char] = match position {
+        JamoPosition::Initial => &[],
+        JamoPosition::Vowel => &['ㅇ'],
+        JamoPosition::Final => &['ㅇ', 'ㅏ'],
+    };
+    for c in before {
+        if let Ok(jamo) = Jamo::from_compatibility_jamo(*c) {
+            composer.push(&jamo);
+        }
+    }
+    composer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::StringComposer;
+
+    fn jamo(c: char) -> KeyOutput {
+        KeyOutput::Jamo(Jamo::from_compatibility_jamo(c).unwrap())
+    }
+
+    #[test]
+    fn dubeolsik_keys() {
+        let layout = Dubeolsik;
+        let tests = vec![
+            ('r', false, jamo('ㄱ')),
+            ('r', true, jamo('ㄲ')),
+            ('t', true, jamo('ㅆ')),
+            ('o', true, jamo('ㅒ')),
+            ('k', true, jamo('ㅏ')),
+            ('m', false, jamo('ㅡ')),
+            ('1', false, KeyOutput::Passthrough('1')),
+            ('/', true, KeyOutput::Passthrough('?')),
+            (' ', false, KeyOutput::Passthrough(' ')),
+            ('한', false, KeyOutput::Passthrough('한')),
+        ];
+        for (key, shift, expected) in tests {
+            assert_eq!(layout.map_key(key, shift), expected, "{key} {shift}");
+        }
+    }
+
+    #[test]
+    fn every_letter_types_jamo() {
+        for key in 'a'..='z' {
+            for shift in [false, true] {
+                assert!(matches!(Dubeolsik.map_key(key, shift), KeyOutput::Jamo(_)));
+            }
+        }
+    }
+
+    #[test]
+    fn chars_round_trip_through_keys() {
+        for c in (' '..='~').chain(['한', '\n']) {
+            let (key, shift) = unshifted(c);
+            assert_eq!(shifted(key, shift), c);
+        }
+        assert_eq!(Dubeolsik.map_char('Q'), jamo('ㅃ'));
+        assert_eq!(Dubeolsik.map_char('?'), KeyOutput::Passthrough('?'));
+    }
+
+    fn type_keys(layout: &impl KeyboardLayout, keys: &str) -> String {
+        let mut composer = StringComposer::new();
+        for key in keys.chars() {
+            match layout.map_char(key) {
+                KeyOutput::PositionedJamo(jamo, position) => {
+                    composer.push_positioned(&jamo, position).unwrap()
+                }
+                KeyOutput::Jamo(jamo) => composer.push_char(jamo.char_compatibility()).unwrap(),
+                KeyOutput::Passthrough(c) => composer.push_char(c).unwrap(),
+            }
+        }
+        composer.as_string().unwrap()
+    }
+
+    #[test]
+    fn sebeolsik_390_typing() {
+        assert_eq!(type_keys(&Sebeolsik390, "mfskgw ;;fj/f2"), "한글 빠왔");
+        assert_eq!(type_keys(&Sebeolsik390, "kf3quf@ J"), "값닭 1");
+        assert_eq!(type_keys(&Sebeolsik390, "kfkf"), "가가");
+        assert_eq!(type_keys(&Dubeolsik, "rkrk"), "가가");
+    }
+
+    #[test]
+    fn builtin_layouts_are_valid() {
+        assert_eq!(validate_layout(&Dubeolsik), Ok(()));
+        assert_eq!(validate_layout(&Sebeolsik390), Ok(()));
+        assert_eq!(validate_layout(&SebeolsikFinal), Ok(()));
+    }
+
+    #[test]
+    fn unplaceable_jamo_rejected() {
+        struct Broken;
+        impl KeyboardLayout for Broken {
+            fn name(&self) -> &str {
+                "Broken"
+            }
+
+            fn map_key(&self, key: char, shift: bool) -> KeyOutput {
+                match (key, shift) {
+                    ('e', true) => Final('ㄸ').output(),
+                    _ => Sebeolsik390.map_key(key, shift),
+                }
+            }
+        }
+        assert_eq!(
+            validate_layout(&Broken),
+            Err(LayoutError::UnplaceableJamo('E', 'ㄸ'))
+        );
+    }
+
+    #[test]
+    fn sebeolsik_final_typing() {
+        assert_eq!(type_keys(&SebeolsikFinal, "kfxjdjfs"), "각이안");
+        assert_eq!(type_keys(&SebeolsikFinal, "uf@ J[-"), "닭 1()");
+    }
+
+    #[test]
+    fn dubeolsik_keystrokes() {
+        let tests = [
+            ("안녕", "dkssud"),
+            ("와", "dhk"),
+            ("값", "rkqt"),
+            ("뷁", "qnpfr"),
+            ("꽃 3개!", "Rhc 3ro!"),
+            ("ㅘ ㄳ ㄲ", "hk rt R"),
+        ];
+        for (text, keys) in tests {
+            assert_eq!(
+                text_to_keystrokes(text, &Dubeolsik).unwrap(),
+                keys,
+                "{text}"
+            );
+        }
+        assert_eq!(
+            text_to_keystrokes("한글€", &Dubeolsik),
+            Err(LayoutError::UntypeableCharacter('€'))
+        );
+    }
+
+    #[test]
+    fn keystrokes_round_trip() {
+        let text = "안녕하세요 값싼 꽃과 닭을 의자에 뒀다 뷁";
+        let results = [
+            round_trip(&Dubeolsik, text),
+            round_trip(&Sebeolsik390, text),
+            round_trip(&SebeolsikFinal, text),
+        ];
+        for (keys, typed) in results {
+            assert_eq!(typed, text, "{keys}");
+        }
+    }
+
+    fn round_trip(layout: &impl KeyboardLayout, text: &str) -> (String, String) {
+        let keys = text_to_keystrokes(text, layout).unwrap();
+        let typed = type_keys(layout, &keys);
+        (keys, typed)
+    }
+
+    #[test]
+    fn custom_layout_definitions() {
+        let definition: String = DUBEOLSIK
+            .iter()
+            .map(|(key, plain, shift)| format!("{key} {plain} {shift}\n"))
+            .collect();
+        let layout = CustomLayout::parse(&definition).unwrap();
+        assert_eq!(layout.name(), "Custom layout");
+        for key in us_qwerty_keys() {
+            for shift in [false, true] {
+                assert_eq!(layout.map_key(key, shift), Dubeolsik.map_key(key, shift));
+            }
+        }
+
+        let layout =
+            CustomLayout::parse("name = Symbols\n\n  # keys\n` final:ㄷ ~\nspace ㅇ\n; : #\n")
+                .unwrap();
+        assert_eq!(layout.name(), "Symbols");
+        assert_eq!(layout.map_key(';', false), KeyOutput::Passthrough(':'));
+        assert_eq!(layout.map_key(';', true), KeyOutput::Passthrough('#'));
+        assert_eq!(
+            layout.map_key(' ', false),
+            KeyOutput::Jamo(Jamo::from_compatibility_jamo('ㅇ').unwrap())
+        );
+        assert_eq!(layout.map_key('`', true), KeyOutput::Passthrough('~'));
+    }
+
+    #[test]
+    fn custom_layout_errors() {
+        let tests = [
+            ("r", LayoutError::InvalidLine(1, "r".to_string())),
+            (
+                "r ㄱ ㄲ ㅋ",
+                LayoutError::InvalidLine(1, "r ㄱ ㄲ ㅋ".to_string()),
+            ),
+            ("\nR ㄱ", LayoutError::UnknownKey(2, "R".to_string())),
+            ("tab ㄱ", LayoutError::UnknownKey(1, "tab".to_string())),
+            ("r ㆆ", LayoutError::UnknownJamo(1, "ㆆ".to_string())),
+            (
+                "r medial:ㅏ",
+                LayoutError::UnknownJamo(1, "medial:ㅏ".to_string()),
+            ),
+            (
+                "r vowel:a",
+                LayoutError::UnknownJamo(1, "vowel:a".to_string()),
+            ),
+            ("r ㄱㄱ", LayoutError::UnknownJamo(1, "ㄱㄱ".to_string())),
+            ("r ㄱ\n# r\nr ㄲ", LayoutError::DuplicateKey(3, 'r', 1)),
+            ("e final:ㄸ", LayoutError::UnplaceableKey(1, 'e', 'ㄸ')),
+            (
+                "r ㄱ\n\ne ㅏ final:ㄸ",
+                LayoutError::UnplaceableKey(3, 'E', 'ㄸ'),
+            ),
+        ];
+        for (definition, error) in tests {
+            assert_eq!(CustomLayout::parse(definition), Err(error), "{definition}");
+        }
+    }
+}
@@ -5,11 +5,62 @@
 /// A module for working with Hangul syllable blocks.
 pub mod block;
 
+/// A module for sorting Hangul text in South and North Korean dictionary order.
+pub mod collation;
+
+/// A module for spelling out numbers followed by Korean counter words.
+pub mod counters;
+
+/// A module for writing monetary amounts in formal Korean styles.
+pub mod currency;
+
+/// A module for formatting dates and times in Korean.
+pub mod datetime;
+
+/// A module for diffing Hangul strings syllable by syllable at the jamo level.
+pub mod diff;
+
+/// A module for measuring edit distances between Hangul strings at the jamo level.
+pub mod distance;
+
+/// A module for grouping entries under ㄱ, ㄴ, ㄷ … index headers.
+pub mod grouping;
+
+/// A module for detecting and converting text typed in the wrong 한/영 input mode.
+pub mod input_mode;
+
 /// A module for working with Hangul jamo characters.
 pub mod jamo;
 
+/// A module for mapping keyboard keys to jamo on Korean keyboard layouts.
+pub mod layout;
+
+/// A module for composing out-of-order (모아치기) 3-beolsik input.
+pub mod moachigi;
+
+/// A module for reading numbers as Sino-Korean and native Korean numerals.
+pub mod numerals;
+
+/// A module for mapping offsets between composed text and its jamo.
+pub mod offsets;
+
+/// A module for matching Hangul text against jamo slot patterns.
+pub mod pattern;
+
+/// A module for typing Hangul with romanized Latin letters.
+pub mod romaja;
+
+/// A module for customizing which jamo combine while composing.
+pub mod rules;
+
+/// A module for searching Hangul text by initial consonants (초성).
+pub mod search;
+
 /// A module for working with strings mixing Hangul and non-Hangul characters.
 pub mod string;
 
+/// A module for indexing Hangul words by jamo for autocomplete.
+pub mod trie;
+
 /// A module for working with Hangul words.
 pub mod word;
@@ -0,0 +1,334 @@
+use std::time::Duration;
+
+use crate::{jamo::*, layout::KeyOutput, rules::*, string::*};
+
+/// Options for composing out-of-order 3-beolsik input with a
+/// `MoachigiComposer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoachigiOptions {
+    /// How long after the first jamo of a syllable other jamo can arrive
+    /// and still be placed in the same syllable, in any order.
+    pub window: Duration,
+}
+
+impl Default for MoachigiOptions {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_millis(50),
+        }
+    }
+}
+
+/// A composer for moachigi (모아치기) input on 3-beolsik layouts, where the
+/// keys of a syllable are pressed nearly at once and their jamo can arrive
+/// in any order, such as a vowel before its initial.
+///
+/// Positioned jamo are collected for the length of the window in
+/// `MoachigiOptions`, starting from the first jamo of a syllable, and are
+/// then ordered into initial, vowel and final before being pushed to a
+/// `StringComposer`. A syllable is also ended early when a jamo arrives for
+/// a slot that is already filled and cannot combine with it, such as a
+/// second initial that does not make a double consonant. Timestamps are
+/// supplied by the caller and only need to be measured from a common start,
+/// such as the time the input field was focused.
+///
+/// **API:**
+/// ```rust
+/// use std::time::Duration;
+/// use hangul_cd::layout::{KeyboardLayout, Sebeolsik390};
+/// use hangul_cd::moachigi::{MoachigiComposer, MoachigiOptions};
+///
+/// let mut composer = MoachigiComposer::new(MoachigiOptions::default());
+///
+/// // ㅏ, ㄴ and ㅎ pressed together arrive out of order but make 한
+/// for (key, ms) in [('f', 0), ('s', 8), ('m', 15)] {
+///     let output = Sebeolsik390.map_char(key);
+///     composer.push_key(output, Duration::from_millis(ms)).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "한");
+///
+/// // ㄱ, ㅡ and ㄹ pressed after the window has passed start a new syllable
+/// for (key, ms) in [('w', 200), ('k', 205), ('g', 210)] {
+///     let output = Sebeolsik390.map_char(key);
+///     composer.push_key(output, Duration::from_millis(ms)).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "한글");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoachigiComposer<R = StandardRules> {
+    options: MoachigiOptions,
+    rules: R,
+    composer: StringComposer<R>,
+    pending: Vec<(Jamo, JamoPosition)>,
+    window_start: Duration,
+}
+
+impl MoachigiComposer {
+    /// Creates a new, empty `MoachigiComposer` with the given options,
+    /// using the `StandardRules`.
+    pub fn new(options: MoachigiOptions) -> Self {
+        Self::with_rules(options, StandardRules)
+    }
+}
+
+impl<R: CompositionRules> MoachigiComposer<R> {
+    /// Creates a new, empty `MoachigiComposer` with the given options that
+    /// decides which jamo in the same slot combine according to the given
+    /// `CompositionRules`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::time::Duration;
+    /// use hangul_cd::layout::{KeyboardLayout, Sebeolsik390};
+    /// use hangul_cd::moachigi::{MoachigiComposer, MoachigiOptions};
+    /// use hangul_cd::rules::NoDoubleInitials;
+    ///
+    /// let mut composer = MoachigiComposer::with_rules(MoachigiOptions::default(), NoDoubleInitials);
+    ///
+    /// // ㄱ pressed twice no longer makes ㄲ, so the second ㄱ starts 가
+    /// for (key, ms) in [('k', 0), ('k', 5), ('f', 10)] {
+    ///     let output = Sebeolsik390.map_char(key);
+    ///     composer.push_key(output, Duration::from_millis(ms)).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "\u{1100}가");
+    /// ```
+    pub fn with_rules(options: MoachigiOptions, rules: R) -> Self {
+        Self {
+            options,
+            composer: StringComposer::with_rules(rules.clone()),
+            rules,
+            pending: Vec::new(),
+            window_start: Duration::ZERO,
+        }
+    }
+
+    /// Pushes a jamo typed for an explicit position at the given time. The
+    /// jamo joins the syllable being collected if it arrived within the
+    /// window and its slot is free, or combines with the jamo already there.
+    /// Otherwise the collected syllable is composed first and the jamo
+    /// starts a new one.
+    pub fn push_positioned(
+        &mut self,
+        letter: &Jamo,
+        position: JamoPosition,
+        timestamp: Duration,
+    ) -> Result<(), StringError> {
+        self.flush_expired(timestamp)?;
+        let slot: Vec<&Jamo> = self
+            .pending
+            .iter()
+            .filter(|(_, p)| *p == position)
+            .map(|(jamo, _)| jamo)
+            .collect();
+        let fits = match slot.as_slice() {
+            [] => true,
+            [prev] => {
+                combines(&self.rules, prev, letter, &position)
+                    || combines(&self.rules, letter, prev, &position)
+            }
+            _ => false,
+        };
+        if !fits {
+            self.flush()?;
+        }
+        if self.pending.is_empty() {
+            self.window_start = timestamp;
+        }
+        self.pending.push((letter.clone(), position));
+        Ok(())
+    }
+
+    /// Pushes the output of a key pressed at the given time. Positioned jamo
+    /// are collected as with `push_positioned`; anything else composes the
+    /// collected syllable first and is then pushed to the composer in order.
+    pub fn push_key(&mut self, output: KeyOutput, timestamp: Duration) -> Result<(), StringError> {
+        match output {
+            KeyOutput::PositionedJamo(jamo, position) => {
+                self.push_positioned(&jamo, position, timestamp)
+            }
+            KeyOutput::Jamo(jamo) => {
+                self.flush()?;
+                self.composer.push_char(jamo.char_compatibility())
+            }
+            KeyOutput::Passthrough(c) => {
+                self.flush()?;
+                self.composer.push_char(c)
+            }
+        }
+    }
+
+    /// Composes the collected syllable if its window has passed by the given
+    /// time. Callers with a timer can use this to commit a syllable when no
+    /// further keys are pressed.
+    pub fn flush_expired(&mut self, now: Duration) -> Result<(), StringError> {
+        if !self.pending.is_empty() && now.saturating_sub(self.window_start) > self.options.window {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Composes the collected syllable regardless of its window.
+    pub fn flush(&mut self) -> Result<(), StringError> {
+        for (jamo, position) in ordered(&self.rules, std::mem::take(&mut self.pending)) {
+            self.composer.push_positioned(&jamo, position)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the composed string, including the syllable still being
+    /// collected as it would be composed now.
+    pub fn as_string(&self) -> Result<String, StringError> {
+        let mut composer = self.composer.clone();
+        for (jamo, position) in ordered(&self.rules, self.pending.clone()) {
+            composer.push_positioned(&jamo, position)?;
+        }
+        composer.as_string()
+    }
+
+    /// Pops the last jamo pushed to the syllable being collected, or the
+    /// last character from the composer if no syllable is being collected.
+    /// See `StringComposer::pop`.
+    pub fn pop(&mut self) -> Result<Option<char>, StringError> {
+        match self.pending.pop() {
+            Some((jamo, position)) => Ok(jamo.char_modern(position)),
+            None => self.composer.pop(),
+        }
+    }
+}
+
+/// Returns whether two jamo typed in the same slot, in the given order,
+/// combine into a double or composite jamo under the given rules.
+fn combines(
+    rules: &impl CompositionRules,
+    first: &Jamo,
+    second: &Jamo,
+    position: &JamoPosition,
+) -> bool {
+    match (first, second, position) {
+        (Jamo::Consonant(a), Jamo::Consonant(b), JamoPosition::Initial) => {
+            rules.combine_initial(a, b).is_some()
+        }
+        (Jamo::Consonant(a), Jamo::Consonant(b), JamoPosition::Final) => {
+            rules.combine_final(a, b).is_some()
+        }
+        (Jamo::Vowel(a), Jamo::Vowel(b), JamoPosition::Vowel) => {
+            rules.combine_vowel(a, b).is_some()
+        }
+        _ => false,
+    }
+}
+
+/// Orders collected jamo into initial, vowel and final, keeping the order
+/// they arrived in within a slot unless only the reverse order combines,
+/// as for ㅏ arriving before ㅗ in ㅘ.
+fn ordered(
+    rules: &impl CompositionRules,
+    mut pending: Vec<(Jamo, JamoPosition)>,
+) -> Vec<(Jamo, JamoPosition)> {
+    pending.sort_by_key(|(_, position)| match position {
+        JamoPosition::Initial => 0,
+        JamoPosition::Vowel => 1,
+        JamoPosition::Final => 2,
+    });
+    for i in 1..pending.len() {
+        let ((a, a_position), (b, b_position)) = (&pending[i - 1], &pending[i]);
+        if a_position == b_position
+            && !combines(rules, a, b, a_position)
+            && combines(rules, b, a, a_position)
+        {
+            pending.swap(i - 1, i);
+        }
+    }
+    pending
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{KeyboardLayout, Sebeolsik390};
+
+    fn type_timed<R: CompositionRules>(composer: &mut MoachigiComposer<R>, keys: &[(char, u64)]) {
+        for (key, ms) in keys {
+            let output = Sebeolsik390.map_char(*key);
+            composer
+                .push_key(output, Duration::from_millis(*ms))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn out_of_order_syllables() {
+        let mut composer = MoachigiComposer::new(MoachigiOptions::default());
+        // 닭: ㄺ, ㅏ, ㄷ; then 과: ㅏ, ㄱ, ㅗ
+        type_timed(&mut composer, &[('x', 0), ('w', 5), ('f', 10), ('u', 20)]);
+        assert_eq!(composer.as_string().unwrap(), "닭");
+        type_timed(&mut composer, &[('f', 100), ('k', 110), ('/', 120)]);
+        assert_eq!(composer.as_string().unwrap(), "닭과");
+        type_timed(&mut composer, &[(' ', 300)]);
+        assert_eq!(composer.as_string().unwrap(), "닭과 ");
+    }
+
+    #[test]
+    fn slot_conflicts_end_syllable() {
+        let mut composer = MoachigiComposer::new(MoachigiOptions::default());
+        // 가나 rolled within one window: the second initial starts 나
+        type_timed(&mut composer, &[('k', 0), ('f', 5), ('h', 10), ('f', 15)]);
+        assert_eq!(composer.as_string().unwrap(), "가나");
+
+        // ㄱ pressed twice in the initial slot makes ㄲ
+        let mut composer = MoachigiComposer::new(MoachigiOptions::default());
+        type_timed(&mut composer, &[('f', 0), ('k', 5), ('k', 10)]);
+        assert_eq!(composer.as_string().unwrap(), "까");
+
+        // A third ㄱ ends the syllable, leaving ㄲ without a vowel
+        let mut composer = MoachigiComposer::new(MoachigiOptions::default());
+        type_timed(&mut composer, &[('k', 0), ('k', 5), ('k', 10)]);
+        assert_eq!(composer.as_string().unwrap(), "\u{1101}\u{1100}");
+        type_timed(&mut composer, &[('f', 15)]);
+        assert_eq!(composer.as_string().unwrap(), "\u{1101}가");
+        composer.flush().unwrap();
+        assert_eq!(composer.as_string().unwrap(), "\u{1101}가");
+    }
+
+    #[test]
+    fn window_expiry() {
+        let options = MoachigiOptions {
+            window: Duration::from_millis(30),
+        };
+        let mut composer = MoachigiComposer::new(options);
+        type_timed(&mut composer, &[('k', 0), ('f', 10)]);
+        // A final typed late still joins the open syllable in order
+        type_timed(&mut composer, &[('s', 100)]);
+        assert_eq!(composer.as_string().unwrap(), "간");
+
+        assert_eq!(composer.pop().unwrap(), Some('ᆫ'));
+        assert_eq!(composer.as_string().unwrap(), "가");
+
+        type_timed(&mut composer, &[('a', 150)]);
+        composer.flush_expired(Duration::from_millis(200)).unwrap();
+        type_timed(&mut composer, &[('j', 210), ('d', 215)]);
+        assert_eq!(composer.as_string().unwrap(), "강이");
+
+        // A lone initial whose window passes is kept as it is
+        let mut composer = MoachigiComposer::new(MoachigiOptions::default());
+        type_timed(&mut composer, &[('k', 0), ('h', 200), ('f', 205)]);
+        assert_eq!(composer.as_string().unwrap(), "\u{1100}나");
+        type_timed(&mut composer, &[('s', 400)]);
+        assert_eq!(composer.as_string().unwrap(), "\u{1100}난");
+    }
+
+    #[test]
+    fn composition_rules() {
+        // ㄱ pressed twice stays two initials without double initials
+        let mut composer =
+            MoachigiComposer::with_rules(MoachigiOptions::default(), NoDoubleInitials);
+        type_timed(&mut composer, &[('k', 0), ('k', 5), ('f', 10)]);
+        assert_eq!(composer.as_string().unwrap(), "\u{1100}가");
+
+        // ㅏ before ㅗ is not reordered into ㅘ without composite vowels
+        let mut composer =
+            MoachigiComposer::with_rules(MoachigiOptions::default(), NoCompositeVowels);
+        type_timed(&mut composer, &[('f', 0), ('k', 5), ('/', 10)]);
+        assert_eq!(composer.as_string().unwrap(), "가ㅗ");
+    }
+}
@@ -0,0 +1,767 @@
+use thiserror::Error;
+
+/// Errors that can occur when reading numbers as Korean number words.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum NumeralError {
+    /// Occurs when a number has no reading in the requested numeral system;
+    /// for example, native Korean numerals have no word for zero.
+    #[error("Number {0} has no native Korean reading")]
+    NoNativeReading(u64),
+
+    /// Occurs when a decimal string could not be parsed as a number.
+    #[error("Could not parse '{0}' as a decimal number")]
+    InvalidDecimal(String),
+
+    /// Occurs when parsing a string that contains no number words.
+    #[error("No number found in input")]
+    EmptyNumber,
+
+    /// Occurs when parsing a string that contains a character which is not
+    /// part of any number word. Contains the character and its char index.
+    #[error("Unrecognized character '{0}' at position {1}")]
+    UnrecognizedCharacter(char, usize),
+
+    /// Occurs when parsing a string in which a number word appears where it
+    /// is not allowed; for example, 십 after 백 has already been closed by
+    /// 십 (십백), or two digits in a row (삼사). Contains the word and its
+    /// char index.
+    #[error("Unexpected number word '{0}' at position {1}")]
+    UnexpectedWord(String, usize),
+
+    /// Occurs when a parsed number does not fit in an `i128`. Contains the
+    /// char index of the word at which the overflow happened.
+    #[error("Number overflows at position {0}")]
+    NumberOverflow(usize),
+}
+
+/// Options for reading numbers with Sino-Korean numerals.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::numerals::{sino_korean, SinoKoreanOptions};
+///
+/// let spaced = SinoKoreanOptions::default();
+/// assert_eq!(sino_korean(1234567, &spaced), "백이십삼만 사천오백육십칠");
+///
+/// let formal = SinoKoreanOptions {
+///     space_by_myriad: false,
+///     explicit_one: true,
+/// };
+/// assert_eq!(sino_korean(1234567, &formal), "일백이십삼만사천오백육십칠");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SinoKoreanOptions {
+    /// Whether to insert a space after each 만-unit group (만, 억, 조, ...).
+    pub space_by_myriad: bool,
+
+    /// Whether to write 일 before 십, 백, 천 and a leading 만, as is done in
+    /// formal or financial writing (일십, 일백, 일만). 일 is always written
+    /// before 억 and larger units.
+    pub explicit_one: bool,
+}
+
+impl Default for SinoKoreanOptions {
+    fn default() -> Self {
+        SinoKoreanOptions {
+            space_by_myriad: true,
+            explicit_one: false,
+        }
+    }
+}
+
+/// The grammatical form of a native Korean numeral.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NativeForm {
+    /// The standalone counting form; ex. 하나, 둘, 스물.
+    Cardinal,
+
+    /// The form used directly before a counter or noun;
+    /// ex. 한 개, 두 명, 스무 살.
+    Attributive,
+}
+
+/// The style used to write native Korean ordinal numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrdinalStyle {
+    /// Ordinals formed with the suffix 째; ex. 첫째, 둘째, 열한째.
+    Jjae,
+
+    /// Ordinals formed with the counter 번째; ex. 첫 번째, 두 번째.
+    Beonjjae,
+}
+
+/// The two Korean numeral systems.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumeralSystem {
+    /// Sino-Korean numerals; ex. 일, 이, 삼.
+    Sino,
+
+    /// Native Korean numerals; ex. 하나, 둘, 셋.
+    Native,
+}
+
+/// The characters used to write Sino-Korean numbers; shared between the
+/// Hangul readings in this module and other scripts such as Hanja.
+pub(crate) struct SinoGlyphs {
+    /// Digits 0 through 9.
+    pub(crate) digits: [&'static str; 10],
+
+    /// Units within a 만-group: none, 10, 100 and 1000.
+    pub(crate) small_units: [&'static str; 4],
+
+    /// Units between 만-groups: none, 10⁴, 10⁸, and so on up to 10³⁶.
+    pub(crate) large_units: [&'static str; 10],
+}
+
+pub(crate) const HANGUL_GLYPHS: SinoGlyphs = SinoGlyphs {
+    digits: ["영", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"],
+    small_units: ["", "십", "백", "천"],
+    large_units: ["", "만", "억", "조", "경", "해", "자", "양", "구", "간"],
+};
+
+const NATIVE_ONES: [&str; 10] = [
+    "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+const NATIVE_ONES_ATTRIBUTIVE: [&str; 10] = [
+    "", "한", "두", "세", "네", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+const NATIVE_TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+const NEGATIVE_PREFIX: &str = "마이너스";
+const DECIMAL_POINT: &str = "점";
+
+/// Reads an integer using Sino-Korean numerals, grouping digits by
+/// 만 (10⁴), 억 (10⁸), 조 (10¹²), 경 (10¹⁶) and larger units.
+/// Negative numbers are prefixed with 마이너스.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::numerals::{sino_korean, SinoKoreanOptions};
+///
+/// let opts = SinoKoreanOptions::default();
+/// assert_eq!(sino_korean(0, &opts), "영");
+/// assert_eq!(sino_korean(10000, &opts), "만");
+/// assert_eq!(sino_korean(120000000, &opts), "일억 이천만");
+/// assert_eq!(sino_korean(-35, &opts), "마이너스 삼십오");
+/// ```
+pub fn sino_korean(n: i128, options: &SinoKoreanOptions) -> String {
+    let magnitude = sino_korean_unsigned(n.unsigned_abs(), options, &HANGUL_GLYPHS);
+    if n < 0 {
+        format!("{NEGATIVE_PREFIX} {magnitude}")
+    } else {
+        magnitude
+    }
+}
+
+/// Reads an unsigned integer with Sino-Korean numerals written in the
+/// given glyphs.
+pub(crate) fn sino_korean_unsigned(
+    n: u128,
+    options: &SinoKoreanOptions,
+    glyphs: &SinoGlyphs,
+) -> String {
+    if n == 0 {
+        return glyphs.digits[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        groups.push((rest % 10000) as u16);
+        rest /= 10000;
+    }
+
+    let mut words = Vec::new();
+    for (unit_index, group) in groups.iter().enumerate().rev() {
+        if *group == 0 {
+            continue;
+        }
+        let mut word = if *group == 1 && unit_index == 1 && !options.explicit_one {
+            String::new()
+        } else {
+            sino_group(*group, options.explicit_one, glyphs)
+        };
+        word.push_str(glyphs.large_units[unit_index]);
+        words.push(word);
+    }
+
+    let separator = if options.space_by_myriad { " " } else { "" };
+    words.join(separator)
+}
+
+/// Reads a single group of up to four digits (1 to 9999) without a large unit.
+fn sino_group(group: u16, explicit_one: bool, glyphs: &SinoGlyphs) -> String {
+    let mut result = String::new();
+    for place in (0..4).rev() {
+        let digit = (group / 10u16.pow(place)) % 10;
+        if digit == 0 {
+            continue;
+        }
+        if digit != 1 || place == 0 || explicit_one {
+            result.push_str(glyphs.digits[digit as usize]);
+        }
+        result.push_str(glyphs.small_units[place as usize]);
+    }
+    result
+}
+
+/// Reads a decimal number given as a string using Sino-Korean numerals.
+/// The integer part is read as with `sino_korean` and each digit after the
+/// decimal point is read individually after 점.
+///
+/// Returns `NumeralError::InvalidDecimal` if the string is not an optionally
+/// negative sequence of ASCII digits with at most one decimal point.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::numerals::{sino_korean_decimal, SinoKoreanOptions};
+///
+/// let opts = SinoKoreanOptions::default();
+/// assert_eq!(sino_korean_decimal("3.14", &opts).unwrap(), "삼 점 일사");
+/// assert_eq!(sino_korean_decimal("-0.05", &opts).unwrap(), "마이너스 영 점 영오");
+/// assert!(sino_korean_decimal("1.2.3", &opts).is_err());
+/// ```
+pub fn sino_korean_decimal(s: &str, options: &SinoKoreanOptions) -> Result<String, NumeralError> {
+    let invalid = || NumeralError::InvalidDecimal(s.to_string());
+
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (integer_part, fraction_part) = match unsigned.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (unsigned, None),
+    };
+
+    let all_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+    if !all_digits(integer_part) || fraction_part.is_some_and(|f| !all_digits(f)) {
+        return Err(invalid());
+    }
+
+    let integer: u128 = integer_part.parse().map_err(|_| invalid())?;
+    let mut result = String::new();
+    if negative {
+        result.push_str(NEGATIVE_PREFIX);
+        result.push(' ');
+    }
+    result.push_str(&sino_korean_unsigned(integer, options, &HANGUL_GLYPHS));
+    if let Some(fraction) = fraction_part {
+        result.push(' ');
+        result.push_str(DECIMAL_POINT);
+        result.push(' ');
+        for c in fraction.chars() {
+            result.push_str(HANGUL_GLYPHS.digits[c.to_digit(10).unwrap_or(0) as usize]);
+        }
+    }
+    Ok(result)
+}
+
+/// Reads a positive integer using native Korean numerals in the given form.
+///
+/// Native numerals only exist for 1 to 99; for larger numbers the hundreds
+/// and above are read in Sino-Korean and the remainder in native Korean, as
+/// in 백스물하나 (121). Returns `NumeralError::NoNativeReading` for zero.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::numerals::{native_korean, NativeForm};
+///
+/// assert_eq!(native_korean(21, NativeForm::Cardinal).unwrap(), "스물하나");
+/// assert_eq!(native_korean(21, NativeForm::Attributive).unwrap(), "스물한");
+/// assert_eq!(native_korean(20, NativeForm::Attributive).unwrap(), "스무");
+/// assert_eq!(native_korean(3, NativeForm::Attributive).unwrap(), "세");
+/// assert!(native_korean(0, NativeForm::Cardinal).is_err());
+/// ```
+pub fn native_korean(n: u64, form: NativeForm) -> Result<String, NumeralError> {
+    if n == 0 {
+        return Err(NumeralError::NoNativeReading(0));
+    }
+
+    let hundreds = n - n % 100;
+    let mut result = if hundreds > 0 {
+        let opts = SinoKoreanOptions {
+            space_by_myriad: false,
+            explicit_one: false,
+        };
+        sino_korean_unsigned(hundreds as u128, &opts, &HANGUL_GLYPHS)
+    } else {
+        String::new()
+    };
+
+    let rem = (n % 100) as usize;
+    let (tens, ones) = (rem / 10, rem % 10);
+    match form {
+        NativeForm::Cardinal => {
+            result.push_str(NATIVE_TENS[tens]);
+            result.push_str(NATIVE_ONES[ones]);
+        }
+        NativeForm::Attributive => {
+            if tens == 2 && ones == 0 {
+                result.push_str("스무");
+            } else {
+                result.push_str(NATIVE_TENS[tens]);
+            }
+            result.push_str(NATIVE_ONES_ATTRIBUTIVE[ones]);
+        }
+    }
+    Ok(result)
+}
+
+/// Reads a positive integer as a native Korean ordinal in the given style.
+/// Returns `NumeralError::NoNativeReading` for zero.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::numerals::{native_ordinal, OrdinalStyle};
+///
+/// assert_eq!(native_ordinal(1, OrdinalStyle::Jjae).unwrap(), "첫째");
+/// assert_eq!(native_ordinal(2, OrdinalStyle::Jjae).unwrap(), "둘째");
+/// assert_eq!(native_ordinal(11, OrdinalStyle::Jjae).unwrap(), "열한째");
+/// assert_eq!(native_ordinal(1, OrdinalStyle::Beonjjae).unwrap(), "첫 번째");
+/// assert_eq!(native_ordinal(2, OrdinalStyle::Beonjjae).unwrap(), "두 번째");
+/// assert_eq!(native_ordinal(20, OrdinalStyle::Beonjjae).unwrap(), "스무 번째");
+/// ```
+pub fn native_ordinal(n: u64, style: OrdinalStyle) -> Result<String, NumeralError> {
+    match style {
+        OrdinalStyle::Jjae => {
+            let stem = match n {
+                1 => "첫".to_string(),
+                2 => "둘".to_string(),
+                _ if n % 10 == 1 || n % 10 == 2 || n % 100 == 20 => {
+                    native_korean(n, NativeForm::Attributive)?
+                }
+                _ => native_korean(n, NativeForm::Cardinal)?,
+            };
+            Ok(format!("{stem}째"))
+        }
+        OrdinalStyle::Beonjjae => {
+            let stem = match n {
+                1 => "첫".to_string(),
+                _ => native_korean(n, NativeForm::Attributive)?,
+            };
+            Ok(format!("{stem} 번째"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberToken {
+    /// A single digit word (삼, 셋) or a run of ASCII digits.
+    Digit(u128),
+    /// 십, 백 or 천.
+    SmallUnit(u128),
+    /// 만, 억, 조 or 경.
+    LargeUnit(u128),
+    /// Native tens words such as 스물 or 서른.
+    NativeTens(u128),
+    /// 마이너스 or '-'.
+    Minus,
+}
+
+const NUMBER_WORDS: [(&str, NumberToken); 43] = [
+    ("마이너스", NumberToken::Minus),
+    ("하나", NumberToken::Digit(1)),
+    ("다섯", NumberToken::Digit(5)),
+    ("여섯", NumberToken::Digit(6)),
+    ("일곱", NumberToken::Digit(7)),
+    ("여덟", NumberToken::Digit(8)),
+    ("아홉", NumberToken::Digit(9)),
+    ("스물", NumberToken::NativeTens(20)),
+    ("스무", NumberToken::NativeTens(20)),
+    ("서른", NumberToken::NativeTens(30)),
+    ("마흔", NumberToken::NativeTens(40)),
+    ("예순", NumberToken::NativeTens(60)),
+    ("일흔", NumberToken::NativeTens(70)),
+    ("여든", NumberToken::NativeTens(80)),
+    ("아흔", NumberToken::NativeTens(90)),
+    ("한", NumberToken::Digit(1)),
+    ("둘", NumberToken::Digit(2)),
+    ("두", NumberToken::Digit(2)),
+    ("셋", NumberToken::Digit(3)),
+    ("세", NumberToken::Digit(3)),
+    ("넷", NumberToken::Digit(4)),
+    ("네", NumberToken::Digit(4)),
+    ("열", NumberToken::NativeTens(10)),
+    ("쉰", NumberToken::NativeTens(50)),
+    ("영", NumberToken::Digit(0)),
+    ("공", NumberToken::Digit(0)),
+    ("일", NumberToken::Digit(1)),
+    ("이", NumberToken::Digit(2)),
+    ("삼", NumberToken::Digit(3)),
+    ("사", NumberToken::Digit(4)),
+    ("오", NumberToken::Digit(5)),
+    ("육", NumberToken::Digit(6)),
+    ("칠", NumberToken::Digit(7)),
+    ("팔", NumberToken::Digit(8)),
+    ("구", NumberToken::Digit(9)),
+    ("십", NumberToken::SmallUnit(10)),
+    ("백", NumberToken::SmallUnit(100)),
+    ("천", NumberToken::SmallUnit(1000)),
+    ("만", NumberToken::LargeUnit(10_000)),
+    ("억", NumberToken::LargeUnit(100_000_000)),
+    ("조", NumberToken::LargeUnit(1_000_000_000_000)),
+    ("경", NumberToken::LargeUnit(10_000_000_000_000_000)),
+    ("-", NumberToken::Minus),
+];
+
+/// Splits the input into number tokens paired with their char index,
+/// skipping whitespace and commas.
+fn tokenize_number(s: &str) -> Result<Vec<(NumberToken, usize, String)>, NumeralError> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    'outer: while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() || c == ',' {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let start = i;
+            let mut value: u128 = 0;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == ',') {
+                if let Some(d) = chars[i].to_digit(10) {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(d as u128))
+                        .ok_or(NumeralError::NumberOverflow(start))?;
+                }
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push((NumberToken::Digit(value), start, text));
+            continue;
+        }
+
+        for (word, token) in NUMBER_WORDS {
+            let len = word.chars().count();
+            if i + len <= chars.len() && chars[i..i + len].iter().copied().eq(word.chars()) {
+                tokens.push((token, i, word.to_string()));
+                i += len;
+                continue 'outer;
+            }
+        }
+        return Err(NumeralError::UnrecognizedCharacter(c, i));
+    }
+    Ok(tokens)
+}
+
+/// Parses Korean number words into an integer. The parser accepts
+/// Sino-Korean numerals, native Korean numerals (in cardinal or attributive
+/// form), ASCII digits, or any mix of them, grouped by 만, 억, 조 and 경.
+/// Whitespace and commas are ignored, and a leading 마이너스 or '-' makes
+/// the number negative.
+///
+/// Errors report the char index of the offending character or word.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::numerals::{parse_korean_number, NumeralError};
+///
+/// assert_eq!(parse_korean_number("삼만 오천"), Ok(35000));
+/// assert_eq!(parse_korean_number("스물하나"), Ok(21));
+/// assert_eq!(parse_korean_number("일억 이천만"), Ok(120000000));
+/// assert_eq!(parse_korean_number("백십"), Ok(110));
+/// assert_eq!(parse_korean_number("3만 5천"), Ok(35000));
+/// assert_eq!(parse_korean_number("마이너스 십"), Ok(-10));
+///
+/// assert_eq!(
+///     parse_korean_number("삼만 오x"),
+///     Err(NumeralError::UnrecognizedCharacter('x', 4))
+/// );
+/// assert_eq!(
+///     parse_korean_number("십백"),
+///     Err(NumeralError::UnexpectedWord("백".to_string(), 1))
+/// );
+/// ```
+pub fn parse_korean_number(s: &str) -> Result<i128, NumeralError> {
+    let tokens = tokenize_number(s)?;
+    if tokens.is_empty() {
+        return Err(NumeralError::EmptyNumber);
+    }
+
+    let mut negative = false;
+    let mut seen_number = false;
+    let mut total: u128 = 0;
+    let mut section: u128 = 0;
+    let mut section_empty = true;
+    let mut current: Option<u128> = None;
+    let mut last_small: Option<u128> = None;
+    let mut last_large: Option<u128> = None;
+
+    for (index, (token, position, text)) in tokens.into_iter().enumerate() {
+        let unexpected = || NumeralError::UnexpectedWord(text.clone(), position);
+        let overflow = || NumeralError::NumberOverflow(position);
+        match token {
+            NumberToken::Minus => {
+                if index != 0 {
+                    return Err(unexpected());
+                }
+                negative = true;
+                continue;
+            }
+            NumberToken::Digit(d) => {
+                if current.is_some() {
+                    return Err(unexpected());
+                }
+                current = Some(d);
+            }
+            NumberToken::SmallUnit(u) | NumberToken::NativeTens(u) => {
+                if last_small.is_some_and(|last| u >= last) {
+                    return Err(unexpected());
+                }
+                // Native tens words carry their own value, so unlike 십, 백
+                // and 천 they cannot multiply a preceding digit
+                let value = match token {
+                    NumberToken::SmallUnit(_) => current.take().unwrap_or(1),
+                    _ if current.is_some() => return Err(unexpected()),
+                    _ => 1,
+                };
+                section = value
+                    .checked_mul(u)
+                    .and_then(|v| v.checked_add(section))
+                    .ok_or_else(overflow)?;
+                last_small = Some(u);
+            }
+            NumberToken::LargeUnit(u) => {
+                if last_large.is_some_and(|last| u >= last) {
+                    return Err(unexpected());
+                }
+                let mut value = section
+                    .checked_add(current.take().unwrap_or(0))
+                    .ok_or_else(overflow)?;
+                if section_empty {
+                    value = value.max(1);
+                }
+                total = value
+                    .checked_mul(u)
+                    .and_then(|v| v.checked_add(total))
+                    .ok_or_else(overflow)?;
+                section = 0;
+                section_empty = true;
+                last_small = None;
+                last_large = Some(u);
+                seen_number = true;
+                continue;
+            }
+        }
+        section_empty = false;
+        seen_number = true;
+    }
+
+    if !seen_number {
+        return Err(NumeralError::EmptyNumber);
+    }
+    let end = s.chars().count();
+    let magnitude = section
+        .checked_add(current.unwrap_or(0))
+        .and_then(|v| v.checked_add(total))
+        .ok_or(NumeralError::NumberOverflow(end))?;
+    let value = i128::try_from(magnitude).map_err(|_| NumeralError::NumberOverflow(end))?;
+    Ok(if negative { -value } else { value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sino_korean_small_numbers() {
+        let opts = SinoKoreanOptions::default();
+        let tests = vec![
+            (1, "일"),
+            (10, "십"),
+            (11, "십일"),
+            (20, "이십"),
+            (100, "백"),
+            (110, "백십"),
+            (1000, "천"),
+            (1001, "천일"),
+            (9999, "구천구백구십구"),
+        ];
+        for (n, expected) in tests {
+            assert_eq!(sino_korean(n, &opts), expected, "Failed on {n}");
+        }
+    }
+
+    #[test]
+    fn sino_korean_large_units() {
+        let opts = SinoKoreanOptions::default();
+        let tests = vec![
+            (10000, "만"),
+            (15000, "만 오천"),
+            (110000, "십일만"),
+            (1234567, "백이십삼만 사천오백육십칠"),
+            (100000000, "일억"),
+            (100010000, "일억 만"),
+            (1000000000000, "일조"),
+            (10000000000000000, "일경"),
+            (
+                u64::MAX as i128,
+                "천팔백사십사경 육천칠백사십사조 칠백삼십칠억 구백오십오만 천육백십오",
+            ),
+        ];
+        for (n, expected) in tests {
+            assert_eq!(sino_korean(n, &opts), expected, "Failed on {n}");
+        }
+    }
+
+    #[test]
+    fn sino_korean_without_spacing() {
+        let opts = SinoKoreanOptions {
+            space_by_myriad: false,
+            explicit_one: false,
+        };
+        assert_eq!(sino_korean(1234567, &opts), "백이십삼만사천오백육십칠");
+        assert_eq!(sino_korean(-10000, &opts), "마이너스 만");
+    }
+
+    #[test]
+    fn sino_korean_explicit_one() {
+        let opts = SinoKoreanOptions {
+            space_by_myriad: false,
+            explicit_one: true,
+        };
+        assert_eq!(sino_korean(123000, &opts), "일십이만삼천");
+        assert_eq!(sino_korean(10000, &opts), "일만");
+    }
+
+    #[test]
+    fn sino_korean_extremes() {
+        let opts = SinoKoreanOptions::default();
+        assert!(sino_korean(i128::MIN, &opts).starts_with("마이너스 백칠십간"));
+        assert!(sino_korean(i128::MAX, &opts).starts_with("백칠십간"));
+    }
+
+    #[test]
+    fn sino_korean_decimal_invalid() {
+        let opts = SinoKoreanOptions::default();
+        for s in ["", "-", ".5", "5.", "1,000", "abc", "--1"] {
+            assert_eq!(
+                sino_korean_decimal(s, &opts),
+                Err(NumeralError::InvalidDecimal(s.to_string())),
+                "Failed on {s:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn native_korean_cardinal_and_attributive() {
+        let tests = vec![
+            (1, "하나", "한"),
+            (2, "둘", "두"),
+            (3, "셋", "세"),
+            (4, "넷", "네"),
+            (10, "열", "열"),
+            (20, "스물", "스무"),
+            (21, "스물하나", "스물한"),
+            (35, "서른다섯", "서른다섯"),
+            (99, "아흔아홉", "아흔아홉"),
+            (100, "백", "백"),
+            (121, "백스물하나", "백스물한"),
+        ];
+        for (n, cardinal, attributive) in tests {
+            assert_eq!(native_korean(n, NativeForm::Cardinal).unwrap(), cardinal);
+            assert_eq!(
+                native_korean(n, NativeForm::Attributive).unwrap(),
+                attributive
+            );
+        }
+    }
+
+    #[test]
+    fn native_ordinals() {
+        let tests = vec![
+            (1, "첫째", "첫 번째"),
+            (2, "둘째", "두 번째"),
+            (3, "셋째", "세 번째"),
+            (10, "열째", "열 번째"),
+            (11, "열한째", "열한 번째"),
+            (12, "열두째", "열두 번째"),
+            (13, "열셋째", "열세 번째"),
+            (20, "스무째", "스무 번째"),
+        ];
+        for (n, jjae, beonjjae) in tests {
+            assert_eq!(native_ordinal(n, OrdinalStyle::Jjae).unwrap(), jjae);
+            assert_eq!(native_ordinal(n, OrdinalStyle::Beonjjae).unwrap(), beonjjae);
+        }
+        assert_eq!(
+            native_ordinal(0, OrdinalStyle::Jjae),
+            Err(NumeralError::NoNativeReading(0))
+        );
+    }
+
+    #[test]
+    fn parse_korean_number_valid() {
+        let tests = vec![
+            ("영", 0),
+            ("만", 10000),
+            ("십만", 100000),
+            ("삼만 오천", 35000),
+            ("스물하나", 21),
+            ("스무", 20),
+            ("열두", 12),
+            ("일억 이천만", 120000000),
+            ("백십", 110),
+            ("백스물한", 121),
+            ("3만 5천", 35000),
+            ("1억 2,000만", 120000000),
+            ("35,000", 35000),
+            ("이천이십육", 2026),
+            ("백이십삼만 사천오백육십칠", 1234567),
+            ("-5", -5),
+            ("마이너스 일억", -100000000),
+        ];
+        for (s, expected) in tests {
+            assert_eq!(parse_korean_number(s), Ok(expected), "Failed on {s}");
+        }
+    }
+
+    #[test]
+    fn parse_korean_number_round_trips_readings() {
+        let opts = SinoKoreanOptions::default();
+        for n in [1, 10, 99, 101, 1010, 10001, 987654321, i64::MAX as i128] {
+            assert_eq!(parse_korean_number(&sino_korean(n, &opts)), Ok(n));
+        }
+        for n in 1..200 {
+            let native = native_korean(n, NativeForm::Cardinal).unwrap();
+            assert_eq!(parse_korean_number(&native), Ok(n as i128));
+        }
+    }
+
+    #[test]
+    fn parse_korean_number_invalid() {
+        let tests = vec![
+            ("", NumeralError::EmptyNumber),
+            ("  ", NumeralError::EmptyNumber),
+            ("-", NumeralError::EmptyNumber),
+            ("삼사", NumeralError::UnexpectedWord("사".to_string(), 1)),
+            ("십백", NumeralError::UnexpectedWord("백".to_string(), 1)),
+            ("만 억", NumeralError::UnexpectedWord("억".to_string(), 2)),
+            (
+                "삼 스물",
+                NumeralError::UnexpectedWord("스물".to_string(), 2),
+            ),
+            (
+                "일 마이너스",
+                NumeralError::UnexpectedWord("마이너스".to_string(), 2),
+            ),
+            ("3삼", NumeralError::UnexpectedWord("삼".to_string(), 1)),
+            ("백a", NumeralError::UnrecognizedCharacter('a', 1)),
+        ];
+        for (s, expected) in tests {
+            assert_eq!(parse_korean_number(s), Err(expected), "Failed on {s:?}");
+        }
+        assert_eq!(
+            parse_korean_number("천경 천경"),
+            Err(NumeralError::UnexpectedWord("경".to_string(), 4))
+        );
+        assert!(matches!(
+            parse_korean_number("999999999999999999999999999999999999999999"),
+            Err(NumeralError::NumberOverflow(0))
+        ));
+    }
+}
@@ -0,0 +1,287 @@
+use std::ops::Range;
+
+use crate::{block::*, jamo::*};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Span {
+    byte_start: usize,
+    jamo_start: usize,
+}
+
+/// A mapping between offsets in composed text and indices into the jamo
+/// stream it decomposes to, so that matches found in jamo space can be
+/// highlighted in the composed text and vice versa.
+///
+/// Every char of the composed text covers one or more consecutive jamo: a
+/// syllable covers its initial, vowel and final, and any other char covers
+/// exactly one. Char offsets count `char`s and byte offsets count UTF-8
+/// bytes, as used for slicing a `&str`.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::block::HangulBlockDecompositionOptions;
+/// use hangul_cd::jamo::JamoUnicodeType;
+/// use hangul_cd::offsets::decompose_with_offsets;
+///
+/// let options = HangulBlockDecompositionOptions {
+///     decompose_composites: true,
+///     jamo_era: JamoUnicodeType::Compatibility,
+/// };
+/// let (jamo, map) = decompose_with_offsets("한국어", &options).unwrap();
+/// assert_eq!(jamo, "ㅎㅏㄴㄱㅜㄱㅇㅓ");
+///
+/// // ㄱㅜ is found at jamo 3..5, in the middle of 국
+/// let range = map.jamo_range_to_text(3..5).unwrap();
+/// assert_eq!(range.chars, 1..2);
+/// assert_eq!(&"한국어"[range.bytes], "국");
+/// assert!(!range.partial_start);
+/// assert!(range.partial_end);
+///
+/// assert_eq!(map.char_to_jamo(2), Some(6));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OffsetMap {
+    spans: Vec<Span>,
+    byte_len: usize,
+    jamo_len: usize,
+}
+
+/// A range of composed text covering a range of jamo, returned by
+/// `OffsetMap::jamo_range_to_text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappedRange {
+    /// The range of char offsets in the composed text.
+    pub chars: Range<usize>,
+
+    /// The range of byte offsets in the composed text.
+    pub bytes: Range<usize>,
+
+    /// Whether the jamo range starts partway into its first char; for
+    /// example, a match on the vowel of a syllable.
+    pub partial_start: bool,
+
+    /// Whether the jamo range ends partway into its last char; for example,
+    /// a match that stops before a syllable's final.
+    pub partial_end: bool,
+}
+
+impl OffsetMap {
+    /// Creates a new, empty `OffsetMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a char of composed text covering the given number of jamo.
+    pub(crate) fn push(&mut self, c: char, jamo_count: usize) {
+        self.spans.push(Span {
+            byte_start: self.byte_len,
+            jamo_start: self.jamo_len,
+        });
+        self.byte_len += c.len_utf8();
+        self.jamo_len += jamo_count;
+    }
+
+    /// Removes the last char of composed text and the jamo it covers.
+    pub(crate) fn pop(&mut self) {
+        if let Some(span) = self.spans.pop() {
+            self.byte_len = span.byte_start;
+            self.jamo_len = span.jamo_start;
+        }
+    }
+
+    /// Returns the number of chars in the composed text.
+    pub fn char_len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns the number of bytes in the composed text.
+    pub fn byte_len(&self) -> usize {
+        self.byte_len
+    }
+
+    /// Returns the number of jamo in the jamo stream.
+    pub fn jamo_len(&self) -> usize {
+        self.jamo_len
+    }
+
+    /// Returns the char offset of the char containing the given jamo, or the
+    /// char length of the text for the end of the jamo stream. Returns `None`
+    /// if the index is out of bounds.
+    pub fn jamo_to_char(&self, jamo: usize) -> Option<usize> {
+        if jamo > self.jamo_len {
+            return None;
+        }
+        if jamo == self.jamo_len {
+            return Some(self.spans.len());
+        }
+        Some(self.spans.partition_point(|span| span.jamo_start <= jamo) - 1)
+    }
+
+    /// Returns the byte offset of the char containing the given jamo, or the
+    /// byte length of the text for the end of the jamo stream. Returns `None`
+    /// if the index is out of bounds.
+    pub fn jamo_to_byte(&self, jamo: usize) -> Option<usize> {
+        let char_offset = self.jamo_to_char(jamo)?;
+        Some(self.char_to_byte(char_offset))
+    }
+
+    /// Returns the index of the first jamo of the char at the given char
+    /// offset, or the jamo length for the end of the text. Returns `None` if
+    /// the offset is out of bounds.
+    pub fn char_to_jamo(&self, char_offset: usize) -> Option<usize> {
+        match self.spans.get(char_offset) {
+            Some(span) => Some(span.jamo_start),
+            None if char_offset == self.spans.len() => Some(self.jamo_len),
+            None => None,
+        }
+    }
+
+    /// Returns the index of the first jamo of the char starting at the given
+    /// byte offset, or the jamo length for the end of the text. Returns `None`
+    /// if the offset is out of bounds or not on a char boundary.
+    pub fn byte_to_jamo(&self, byte_offset: usize) -> Option<usize> {
+        if byte_offset == self.byte_len {
+            return Some(self.jamo_len);
+        }
+        let index = self
+            .spans
+            .binary_search_by_key(&byte_offset, |span| span.byte_start)
+            .ok()?;
+        Some(self.spans[index].jamo_start)
+    }
+
+    /// Returns the range of composed text covering a range of jamo. Chars
+    /// that are only partly covered are included in full, and flagged by
+    /// `partial_start` and `partial_end`. Returns `None` if the range is out
+    /// of bounds.
+    pub fn jamo_range_to_text(&self, jamo: Range<usize>) -> Option<MappedRange> {
+        if jamo.start > jamo.end || jamo.end > self.jamo_len {
+            return None;
+        }
+        let start = self.jamo_to_char(jamo.start)?;
+        let end = if jamo.end > jamo.start {
+            self.jamo_to_char(jamo.end - 1)? + 1
+        } else {
+            start
+        };
+        Some(MappedRange {
+            chars: start..end,
+            bytes: self.char_to_byte(start)..self.char_to_byte(end),
+            partial_start: self.char_to_jamo(start) != Some(jamo.start),
+            partial_end: jamo.end > jamo.start && self.char_to_jamo(end) != Some(jamo.end),
+        })
+    }
+
+    /// Returns the range of jamo covering a range of chars in the composed
+    /// text. Returns `None` if the range is out of bounds.
+    pub fn char_range_to_jamo(&self, chars: Range<usize>) -> Option<Range<usize>> {
+        if chars.start > chars.end {
+            return None;
+        }
+        Some(self.char_to_jamo(chars.start)?..self.char_to_jamo(chars.end)?)
+    }
+
+    fn char_to_byte(&self, char_offset: usize) -> usize {
+        self.spans
+            .get(char_offset)
+            .map_or(self.byte_len, |span| span.byte_start)
+    }
+}
+
+/// Returns the number of jamo the given letters cover once double and
+/// composite jamo are split into their parts.
+pub(crate) fn jamo_count(letters: &[Jamo]) -> usize {
+    letters
+        .iter()
+        .map(|letter| match letter {
+            Jamo::CompositeConsonant(_) | Jamo::CompositeVowel(_) => 2,
+            Jamo::Consonant(_) | Jamo::Vowel(_) => 1,
+        })
+        .sum()
+}
+
+/// Decomposes every Hangul syllable in a string into jamo according to the
+/// given options, keeping all other characters unchanged. Returns the jamo
+/// stream along with the `OffsetMap` from the original string to it.
+/// Returns an error if a syllable cannot be decomposed with the options.
+pub fn decompose_with_offsets(
+    s: &str,
+    options: &HangulBlockDecompositionOptions,
+) -> Result<(String, OffsetMap), BlockError> {
+    let mut jamo = String::new();
+    let mut map = OffsetMap::new();
+    for c in s.chars() {
+        match HangulBlock::from_char(c) {
+            Ok(block) => {
+                let decomposed = block.decomposed_vec(options)?;
+                map.push(c, decomposed.len());
+                jamo.extend(decomposed);
+            }
+            Err(_) => {
+                map.push(c, 1);
+                jamo.push(c);
+            }
+        }
+    }
+    Ok((jamo, map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compatibility(decompose_composites: bool) -> HangulBlockDecompositionOptions {
+        HangulBlockDecompositionOptions {
+            decompose_composites,
+            jamo_era: JamoUnicodeType::Compatibility,
+        }
+    }
+
+    #[test]
+    fn offsets_round_trip() {
+        let text = "a값 과";
+        let (jamo, map) = decompose_with_offsets(text, &compatibility(true)).unwrap();
+        assert_eq!(jamo, "aㄱㅏㅂㅅ ㄱㅗㅏ");
+        assert_eq!((map.char_len(), map.byte_len(), map.jamo_len()), (4, 8, 9));
+
+        let chars = [0, 1, 1, 1, 1, 2, 3, 3, 3, 4];
+        let bytes = [0, 1, 1, 1, 1, 4, 5, 5, 5, 8];
+        for (j, (c, b)) in chars.iter().zip(bytes).enumerate() {
+            assert_eq!(map.jamo_to_char(j), Some(*c));
+            assert_eq!(map.jamo_to_byte(j), Some(b));
+        }
+        assert_eq!(map.jamo_to_char(10), None);
+
+        assert_eq!(map.byte_to_jamo(4), Some(5));
+        assert_eq!(map.byte_to_jamo(2), None);
+        assert_eq!(map.byte_to_jamo(8), Some(9));
+        assert_eq!(map.char_range_to_jamo(1..3), Some(1..6));
+        assert_eq!(map.char_range_to_jamo(1..5), None);
+    }
+
+    #[test]
+    fn composites_kept_whole() {
+        let (jamo, map) = decompose_with_offsets("값과", &compatibility(false)).unwrap();
+        assert_eq!(jamo, "ㄱㅏㅄㄱㅘ");
+        assert_eq!(map.char_to_jamo(1), Some(3));
+    }
+
+    #[test]
+    fn partial_ranges() {
+        let (_, map) = decompose_with_offsets("한국어", &compatibility(true)).unwrap();
+        let range = map.jamo_range_to_text(1..4).unwrap();
+        assert_eq!(range.chars, 0..2);
+        assert_eq!(range.bytes, 0..6);
+        assert!(range.partial_start && range.partial_end);
+
+        let range = map.jamo_range_to_text(3..6).unwrap();
+        assert_eq!(range.chars, 1..2);
+        assert!(!range.partial_start && !range.partial_end);
+
+        let range = map.jamo_range_to_text(4..4).unwrap();
+        assert_eq!(range.chars, 1..1);
+        assert!(range.partial_start && !range.partial_end);
+
+        assert_eq!(map.jamo_range_to_text(4..9), None);
+    }
+}
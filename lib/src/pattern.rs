@@ -0,0 +1,632 @@
+use std::ops::Range;
+
+use thiserror::Error;
+
+use crate::{block::*, jamo::*};
+
+/// Errors that can occur when compiling a `JamoPattern`.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PatternError {
+    /// Occurs when compiling a pattern with no atoms.
+    #[error("Pattern is empty")]
+    EmptyPattern,
+
+    /// Occurs when a `{` or `[` is never closed. Contains the char index of
+    /// the opening bracket.
+    #[error("Unclosed '{0}' at position {1}")]
+    Unclosed(char, usize),
+
+    /// Occurs when a syllable does not contain exactly three slots. Contains
+    /// the char index of the syllable's opening brace.
+    #[error("Syllable at position {0} must have an initial, vowel and final slot")]
+    WrongSlotCount(usize),
+
+    /// Occurs when a jamo cannot appear in the slot it was written in; for
+    /// example, a vowel in the initial slot or ㄸ in the final slot.
+    /// Contains the jamo, the slot and its char index.
+    #[error("'{0}' cannot be used in {1:?} position at position {2}")]
+    JamoInInvalidPosition(char, JamoPosition, usize),
+
+    /// Occurs when a character that is neither a jamo nor a slot wildcard
+    /// appears inside a syllable. Contains the character and its char index.
+    #[error("Unexpected character '{0}' in syllable at position {1}")]
+    UnexpectedCharacter(char, usize),
+
+    /// Occurs when a pattern ends with an unescaped backslash.
+    #[error("Pattern ends with an unfinished escape")]
+    DanglingEscape,
+
+    /// Occurs when a quantifier does not follow an atom, such as at the
+    /// start of a pattern or after another quantifier. Contains the
+    /// quantifier and its char index.
+    #[error("Quantifier '{0}' at position {1} does not follow an atom")]
+    NothingToRepeat(char, usize),
+
+    /// Occurs when every atom of a pattern is optional, so that it would
+    /// match the empty string everywhere.
+    #[error("Pattern matches the empty string")]
+    MatchesEmpty,
+}
+
+/// The set of jamo accepted in one slot of a syllable. `None` stands for
+/// a missing final.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SlotClass {
+    Any,
+    Set(Vec<Option<Jamo>>),
+    NotSet(Vec<Option<Jamo>>),
+}
+
+impl SlotClass {
+    fn matches(&self, jamo: &Option<Jamo>) -> bool {
+        match self {
+            SlotClass::Any => true,
+            SlotClass::Set(set) => set.contains(jamo),
+            SlotClass::NotSet(set) => !set.contains(jamo),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Atom {
+    Literal(char),
+    AnyChar,
+    Syllable {
+        initial: SlotClass,
+        vowel: SlotClass,
+        final_optional: SlotClass,
+    },
+}
+
+/// An atom along with how many times in a row it must match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Piece {
+    atom: Atom,
+    min: usize,
+    max: usize,
+}
+
+impl Atom {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Atom::Literal(l) => *l == c,
+            Atom::AnyChar => true,
+            Atom::Syllable {
+                initial,
+                vowel,
+                final_optional,
+            } => match HangulBlock::from_char(c) {
+                Ok(block) => {
+                    initial.matches(&Some(block.initial))
+                        && vowel.matches(&Some(block.vowel))
+                        && final_optional.matches(&block.final_optional)
+                }
+                Err(_) => false,
+            },
+        }
+    }
+}
+
+/// A compiled pattern matching Hangul text slot by slot.
+///
+/// Each atom of a pattern matches one character:
+/// - `{initial vowel final}` matches a composed syllable whose three slots
+///   each match their class.
+/// - `.` matches any character.
+/// - `\` escapes the next character, which is then matched literally.
+/// - Any other character matches itself.
+///
+/// An atom can be followed by a quantifier to match a run of characters:
+/// `*` for zero or more, `+` for one or more and `?` for zero or one.
+/// Each match is the longest one starting at the first position where the
+/// pattern matches, and the text is scanned once, so matching takes time
+/// linear in the length of the text for any pattern. Outside of syllables,
+/// `*`, `+` and `?` must be escaped to be matched literally.
+///
+/// Each slot class is one of:
+/// - A single jamo, such as `ㄱ`, `ㅘ` or `ㄺ`.
+/// - `[...]`, any one of the listed jamo, or `[^...]`, any jamo not listed.
+/// - `*`, anything; in the final slot this includes no final.
+/// - `_`, no final (final slot only); `_` can also be listed inside brackets.
+/// - `+`, any final (final slot only).
+///
+/// Matches are reported as ranges of char indices into the text.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::pattern::JamoPattern;
+///
+/// // Any syllable with vowel ㅏ and no final
+/// let pattern = JamoPattern::new("{*ㅏ_}").unwrap();
+/// assert_eq!(pattern.find("감자가 있다"), Some(1..2));
+///
+/// // Initial ㄱ or ㄲ, any vowel, final ㅇ
+/// let pattern = JamoPattern::new("{[ㄱㄲ]*ㅇ}").unwrap();
+/// let found: Vec<_> = pattern.find_iter("공간 꽁치 봉지").collect();
+/// assert_eq!(found, vec![0..1, 3..4]);
+///
+/// // Literal text can be mixed with syllables
+/// let pattern = JamoPattern::new("{ㅎ*+}다").unwrap();
+/// assert_eq!(pattern.replace_all("했다 한다 하다", "□"), "□ □ 하다");
+///
+/// // Initial ㄱ or ㄲ, followed by any syllables up to one ending in ㅇ
+/// let pattern = JamoPattern::new("{[ㄱㄲ]**}{***}*{**ㅇ}").unwrap();
+/// let found: Vec<_> = pattern.find_iter("가방 꼬마 고양이 꽃등").collect();
+/// assert_eq!(found, vec![0..2, 6..8, 10..12]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JamoPattern {
+    pieces: Vec<Piece>,
+}
+
+impl JamoPattern {
+    /// Compiles a pattern. Returns a `PatternError` describing the first
+    /// problem found if the pattern is invalid.
+    pub fn new(pattern: &str) -> Result<Self, PatternError> {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut pieces: Vec<Piece> = Vec::new();
+        let mut repeatable = false;
+        let mut i = 0;
+        while i < chars.len() {
+            let atom = match chars[i] {
+                q @ ('*' | '+' | '?') => {
+                    let piece = pieces
+                        .last_mut()
+                        .filter(|_| repeatable)
+                        .ok_or(PatternError::NothingToRepeat(q, i))?;
+                    (piece.min, piece.max) = match q {
+                        '*' => (0, usize::MAX),
+                        '+' => (1, usize::MAX),
+                        _ => (0, 1),
+                    };
+                    repeatable = false;
+                    i += 1;
+                    continue;
+                }
+                '{' => {
+                    let (atom, end) = parse_syllable(&chars, i)?;
+                    i = end;
+                    atom
+                }
+                '.' => Atom::AnyChar,
+                '\\' => {
+                    i += 1;
+                    Atom::Literal(*chars.get(i).ok_or(PatternError::DanglingEscape)?)
+                }
+                c => Atom::Literal(c),
+            };
+            pieces.push(Piece {
+                atom,
+                min: 1,
+                max: 1,
+            });
+            repeatable = true;
+            i += 1;
+        }
+        if pieces.is_empty() {
+            return Err(PatternError::EmptyPattern);
+        }
+        if pieces.iter().all(|piece| piece.min == 0) {
+            return Err(PatternError::MatchesEmpty);
+        }
+        Ok(JamoPattern { pieces })
+    }
+
+    /// Returns `true` if the pattern matches anywhere in the text.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+
+    /// Returns the char range of the first match in the text, if any.
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        self.find_iter(text).next()
+    }
+
+    /// Returns an iterator over the char ranges of all non-overlapping
+    /// matches in the text, in order.
+    pub fn find_iter(&self, text: &str) -> Matches<'_> {
+        Matches {
+            pattern: self,
+            chars: text.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    /// Returns a copy of the text with the first match replaced.
+    pub fn replace(&self, text: &str, replacement: &str) -> String {
+        self.replace_n(text, replacement, 1)
+    }
+
+    /// Returns a copy of the text with every match replaced.
+    pub fn replace_all(&self, text: &str, replacement: &str) -> String {
+        self.replace_n(text, replacement, usize::MAX)
+    }
+
+    fn replace_n(&self, text: &str, replacement: &str, limit: usize) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut result = String::new();
+        let mut last = 0;
+        for range in self.find_iter(text).take(limit) {
+            result.extend(&chars[last..range.start]);
+            result.push_str(replacement);
+            last = range.end;
+        }
+        result.extend(&chars[last..]);
+        result
+    }
+
+    /// Returns the leftmost-longest match starting at or after `from`, if
+    /// any.
+    ///
+    /// The pattern is run as an automaton whose states are a piece and how
+    /// many times it has matched so far, counted up to the point where the
+    /// count no longer matters. Every state remembers the earliest start of
+    /// the matches that reach it, so each character of the text is looked
+    /// at once per state however the quantifiers nest.
+    fn find_from(&self, chars: &[char], from: usize) -> Option<Range<usize>> {
+        let states = States::new(&self.pieces);
+        let mut current = vec![None; states.len()];
+        let mut best: Option<Range<usize>> = None;
+        for pos in from..=chars.len() {
+            if best.is_none() {
+                states.enter(&mut current, 0, 0, pos);
+            }
+            if let Some(start) = current[states.accept()]
+                && best.as_ref().is_none_or(|best| start <= best.start)
+            {
+                best = Some(start..pos);
+            }
+            if let Some(best) = &best {
+                // Later starts can no longer give the leftmost match
+                current
+                    .iter_mut()
+                    .filter(|start| start.is_some_and(|start| start > best.start))
+                    .for_each(|start| *start = None);
+                if current.iter().all(Option::is_none) {
+                    break;
+                }
+            }
+            let Some(&c) = chars.get(pos) else {
+                break;
+            };
+
+            let mut next = vec![None; states.len()];
+            for (piece_index, piece) in self.pieces.iter().enumerate() {
+                if !piece.atom.matches(c) {
+                    continue;
+                }
+                for count in 0..=states.cap(piece_index) {
+                    if let Some(start) = current[states.index(piece_index, count)]
+                        && count < piece.max
+                    {
+                        states.enter(&mut next, piece_index, count + 1, start);
+                    }
+                }
+            }
+            current = next;
+        }
+        best
+    }
+}
+
+/// The states of the automaton a `JamoPattern` is run as: one for each
+/// piece and count of matches of it up to its minimum, or up to its maximum
+/// if it is finite, and a final accepting state.
+struct States<'p> {
+    pieces: &'p [Piece],
+    offsets: Vec<usize>,
+}
+
+impl<'p> States<'p> {
+    fn new(pieces: &'p [Piece]) -> Self {
+        let mut offsets = Vec::with_capacity(pieces.len() + 1);
+        let mut total = 0;
+        for piece in pieces {
+            offsets.push(total);
+            total += Self::piece_cap(piece) + 1;
+        }
+        offsets.push(total);
+        States { pieces, offsets }
+    }
+
+    fn piece_cap(piece: &Piece) -> usize {
+        if piece.max == usize::MAX {
+            piece.min
+        } else {
+            piece.max
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.offsets[self.pieces.len()] + 1
+    }
+
+    fn cap(&self, piece_index: usize) -> usize {
+        Self::piece_cap(&self.pieces[piece_index])
+    }
+
+    fn index(&self, piece_index: usize, count: usize) -> usize {
+        self.offsets[piece_index] + count.min(self.cap(piece_index))
+    }
+
+    fn accept(&self) -> usize {
+        self.offsets[self.pieces.len()]
+    }
+
+    /// Enters the state for a piece matched `count` times with a match
+    /// started at `start`, and every state reachable from it without
+    /// reading a character, keeping the earliest start of each.
+    fn enter(&self, states: &mut [Option<usize>], piece_index: usize, count: usize, start: usize) {
+        let mut piece_index = piece_index;
+        let mut count = count;
+        loop {
+            if piece_index == self.pieces.len() {
+                let accept = self.accept();
+                if states[accept].is_none_or(|existing| start < existing) {
+                    states[accept] = Some(start);
+                }
+                return;
+            }
+            let index = self.index(piece_index, count);
+            if states[index].is_some_and(|existing| existing <= start) {
+                return;
+            }
+            states[index] = Some(start);
+            if count < self.pieces[piece_index].min {
+                return;
+            }
+            piece_index += 1;
+            count = 0;
+        }
+    }
+}
+
+/// An iterator over the matches of a `JamoPattern` in a string, created by
+/// `JamoPattern::find_iter`.
+#[derive(Debug)]
+pub struct Matches<'p> {
+    pattern: &'p JamoPattern,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = self.pattern.find_from(&self.chars, self.pos);
+        self.pos = match &found {
+            Some(range) => range.end,
+            None => self.chars.len(),
+        };
+        found
+    }
+}
+
+/// Parses a `{...}` syllable starting at `open`, returning the atom and the
+/// index of the closing brace.
+fn parse_syllable(chars: &[char], open: usize) -> Result<(Atom, usize), PatternError> {
+    let positions = [
+        JamoPosition::Initial,
+        JamoPosition::Vowel,
+        JamoPosition::Final,
+    ];
+    let mut slots = Vec::new();
+    let mut i = open + 1;
+    loop {
+        match chars.get(i) {
+            None => return Err(PatternError::Unclosed('{', open)),
+            Some('}') => break,
+            Some(_) => {
+                let position = positions
+                    .get(slots.len())
+                    .ok_or(PatternError::WrongSlotCount(open))?;
+                let (slot, end) = parse_slot(chars, i, position)?;
+                slots.push(slot);
+                i = end + 1;
+            }
+        }
+    }
+    let [initial, vowel, final_optional]: [SlotClass; 3] = slots
+        .try_into()
+        .map_err(|_| PatternError::WrongSlotCount(open))?;
+    Ok((
+        Atom::Syllable {
+            initial,
+            vowel,
+            final_optional,
+        },
+        i,
+    ))
+}
+
+/// Parses one slot class starting at `start`, returning the class and the
+/// index of its last character.
+fn parse_slot(
+    chars: &[char],
+    start: usize,
+    position: &JamoPosition,
+) -> Result<(SlotClass, usize), PatternError> {
+    match chars[start] {
+        '*' => Ok((SlotClass::Any, start)),
+        '[' => {
+            let negated = chars.get(start + 1) == Some(&'^');
+            let mut set = Vec::new();
+            let mut i = if negated { start + 2 } else { start + 1 };
+            loop {
+                match chars.get(i) {
+                    None => return Err(PatternError::Unclosed('[', start)),
+                    Some(']') => break,
+                    Some(c) => set.push(parse_slot_jamo(*c, i, position)?),
+                }
+                i += 1;
+            }
+            let class = if negated {
+                SlotClass::NotSet(set)
+            } else {
+                SlotClass::Set(set)
+            };
+            Ok((class, i))
+        }
+        '+' if *position == JamoPosition::Final => Ok((SlotClass::NotSet(vec![None]), start)),
+        c => Ok((
+            SlotClass::Set(vec![parse_slot_jamo(c, start, position)?]),
+            start,
+        )),
+    }
+}
+
+fn parse_slot_jamo(
+    c: char,
+    index: usize,
+    position: &JamoPosition,
+) -> Result<Option<Jamo>, PatternError> {
+    if c == '_' && *position == JamoPosition::Final {
+        return Ok(None);
+    }
+    let jamo = match Character::from_char(c) {
+        Ok(Character::Hangul(jamo)) => jamo,
+        _ => return Err(PatternError::UnexpectedCharacter(c, index)),
+    };
+    let valid = match (&jamo, position) {
+        (Jamo::Consonant(_), JamoPosition::Initial | JamoPosition::Final) => true,
+        (Jamo::CompositeConsonant(composite), JamoPosition::Initial) => {
+            composite.is_valid_initial()
+        }
+        (Jamo::CompositeConsonant(composite), JamoPosition::Final) => composite.is_valid_final(),
+        (Jamo::Vowel(_) | Jamo::CompositeVowel(_), JamoPosition::Vowel) => true,
+        _ => false,
+    };
+    if valid {
+        Ok(Some(jamo))
+    } else {
+        Err(PatternError::JamoInInvalidPosition(
+            c,
+            position.clone(),
+            index,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slot_classes() {
+        let tests = vec![
+            ("{ㄱㅏ_}", "가각까", vec![(0, 1)]),
+            ("{ㄱㅏ*}", "가각까", vec![(0, 1), (1, 2)]),
+            ("{ㄱㅏ+}", "가각까", vec![(1, 2)]),
+            ("{*ㅏ[ㄱ_]}", "가각간", vec![(0, 1), (1, 2)]),
+            ("{[^ㄱ]**}", "가나ㄷ다", vec![(1, 2), (3, 4)]),
+            ("{ㄸ[ㅗㅘ]ㄺ}", "똙똵돍", vec![(0, 1), (1, 2)]),
+            ("{***}", "a가ㄱ", vec![(1, 2)]),
+        ];
+        for (pattern, text, expected) in tests {
+            let pattern = JamoPattern::new(pattern).unwrap();
+            let found: Vec<(usize, usize)> =
+                pattern.find_iter(text).map(|r| (r.start, r.end)).collect();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn literals_and_wildcards() {
+        let pattern = JamoPattern::new("a.{*ㅣ_}").unwrap();
+        assert_eq!(pattern.find("xxa-기"), Some(2..5));
+        assert!(!pattern.is_match("a-길"));
+
+        let pattern = JamoPattern::new("\\{{ㅇ**}\\}").unwrap();
+        assert_eq!(pattern.find("값 {안}"), Some(2..5));
+    }
+
+    #[test]
+    fn replacement() {
+        let pattern = JamoPattern::new("{*ㅔ*}").unwrap();
+        assert_eq!(pattern.replace("네 개 세 개", "_"), "_ 개 세 개");
+        assert_eq!(pattern.replace_all("네 개 세 개", "_"), "_ 개 _ 개");
+        assert_eq!(pattern.replace_all("없음", "_"), "없음");
+    }
+
+    #[test]
+    fn quantifiers() {
+        let tests = vec![
+            (
+                "{ㄱ**}{***}*{**ㅇ}",
+                "가방 가 강 고양이",
+                vec![(0, 2), (7, 9)],
+            ),
+            ("{ㄱ**}{***}*", "가방 가 강", vec![(0, 2), (3, 4), (5, 6)]),
+            ("{*ㅏ*}+다", "갔다 가방다 먹다", vec![(0, 2), (3, 6)]),
+            ("ㅋ+", "ㅋ ㅋㅋㅋ!", vec![(0, 1), (2, 5)]),
+            ("{ㅇㅏ_}?{ㄴㅕ*}", "안녕 아녀", vec![(1, 2), (3, 5)]),
+            ("a.*b", "a-b-b c", vec![(0, 5)]),
+            ("\\*+", "a**b", vec![(1, 3)]),
+        ];
+        for (pattern, text, expected) in tests {
+            let pattern = JamoPattern::new(pattern).unwrap();
+            let found: Vec<(usize, usize)> =
+                pattern.find_iter(text).map(|r| (r.start, r.end)).collect();
+            assert_eq!(found, expected);
+        }
+
+        let pattern = JamoPattern::new("{ㄱ**}{***}*{**ㅇ}").unwrap();
+        assert_eq!(pattern.replace_all("가방 가 강 고양이", "□"), "□ 가 강 □이");
+        assert_eq!(pattern.replace("고양 가방", "□"), "□ 가방");
+    }
+
+    #[test]
+    fn pathological_patterns_run_in_linear_time() {
+        let text = "가".repeat(8000);
+        for pattern in [
+            ".*.*.*.*x",
+            "{***}*{***}*{***}*{***}*ㄱ",
+            "가?가?가?가?가*가*x",
+        ] {
+            let pattern = JamoPattern::new(pattern).unwrap();
+            let start = std::time::Instant::now();
+            assert_eq!(pattern.find(&text), None);
+            assert!(
+                start.elapsed() < std::time::Duration::from_secs(1),
+                "{pattern:?}"
+            );
+        }
+
+        let pattern = JamoPattern::new("가+가+가+나").unwrap();
+        let text = format!("{}나", "가".repeat(8000));
+        assert_eq!(pattern.find(&text), Some(0..8001));
+    }
+
+    #[test]
+    fn compile_errors() {
+        let tests = vec![
+            ("", PatternError::EmptyPattern),
+            ("가{ㄱㅏ", PatternError::Unclosed('{', 1)),
+            ("{[ㄱㄲ", PatternError::Unclosed('[', 1)),
+            ("{ㄱㅏ}", PatternError::WrongSlotCount(0)),
+            ("{ㄱㅏㄴㄴ}", PatternError::WrongSlotCount(0)),
+            (
+                "{ㅏㅏ_}",
+                PatternError::JamoInInvalidPosition('ㅏ', JamoPosition::Initial, 1),
+            ),
+            (
+                "{ㄱㅏㄸ}",
+                PatternError::JamoInInvalidPosition('ㄸ', JamoPosition::Final, 3),
+            ),
+            (
+                "{ㄳㅏ_}",
+                PatternError::JamoInInvalidPosition('ㄳ', JamoPosition::Initial, 1),
+            ),
+            ("{_ㅏ_}", PatternError::UnexpectedCharacter('_', 1)),
+            ("{ㄱa_}", PatternError::UnexpectedCharacter('a', 2)),
+            ("가\\", PatternError::DanglingEscape),
+            ("*가", PatternError::NothingToRepeat('*', 0)),
+            ("가+?", PatternError::NothingToRepeat('?', 2)),
+            ("{ㄱ**}?.*", PatternError::MatchesEmpty),
+        ];
+        for (pattern, expected) in tests {
+            assert_eq!(JamoPattern::new(pattern), Err(expected), "{pattern}");
+        }
+    }
+}
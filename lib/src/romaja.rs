@@ -0,0 +1,556 @@
+use crate::{jamo::*, rules::*, word::*};
+
+/// The Latin spellings of each jamo, following the initial spellings of
+/// the Revised Romanization of Korean.
+const ROMAJA: [(&str, char); 41] = [
+    ("g", 'ㄱ'),
+    ("kk", 'ㄲ'),
+    ("n", 'ㄴ'),
+    ("d", 'ㄷ'),
+    ("tt", 'ㄸ'),
+    ("r", 'ㄹ'),
+    ("l", 'ㄹ'),
+    ("m", 'ㅁ'),
+    ("b", 'ㅂ'),
+    ("pp", 'ㅃ'),
+    ("s", 'ㅅ'),
+    ("ss", 'ㅆ'),
+    ("ng", 'ㅇ'),
+    ("j", 'ㅈ'),
+    ("jj", 'ㅉ'),
+    ("ch", 'ㅊ'),
+    ("k", 'ㅋ'),
+    ("t", 'ㅌ'),
+    ("p", 'ㅍ'),
+    ("h", 'ㅎ'),
+    ("a", 'ㅏ'),
+    ("ae", 'ㅐ'),
+    ("ya", 'ㅑ'),
+    ("yae", 'ㅒ'),
+    ("eo", 'ㅓ'),
+    ("e", 'ㅔ'),
+    ("yeo", 'ㅕ'),
+    ("ye", 'ㅖ'),
+    ("o", 'ㅗ'),
+    ("wa", 'ㅘ'),
+    ("wae", 'ㅙ'),
+    ("oe", 'ㅚ'),
+    ("yo", 'ㅛ'),
+    ("u", 'ㅜ'),
+    ("wo", 'ㅝ'),
+    ("we", 'ㅞ'),
+    ("wi", 'ㅟ'),
+    ("yu", 'ㅠ'),
+    ("eu", 'ㅡ'),
+    ("ui", 'ㅢ'),
+    ("i", 'ㅣ'),
+];
+
+/// The final spellings of the Revised Romanization that differ from the
+/// initial ones, used after a vowel when no vowel follows.
+const FINALS: [(&str, char); 3] = [("k", 'ㄱ'), ("t", 'ㄷ'), ("p", 'ㅂ')];
+
+/// The character that separates syllables without being typed itself.
+const SYLLABLE_BREAK: char = '\'';
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum UnitKind {
+    Consonant,
+    Vowel,
+    Break,
+}
+
+/// A jamo or syllable break typed into the current word, and the letters
+/// it pushed to the word composer, with the position they were pushed in
+/// if it was explicit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Unit {
+    kind: UnitKind,
+    output: char,
+    letters: Vec<(Jamo, Option<JamoPosition>)>,
+}
+
+/// A composer for typing Hangul with romanized Latin letters, for learners
+/// without a Korean keyboard, so that typing "annyeong" shows 안, then 아녀,
+/// then 안녕 as the keys are pressed.
+///
+/// Jamo are typed with their Revised Romanization spellings: g, kk, n, d,
+/// tt, r or l, m, b, pp, s, ss, j, jj, ch, k, t, p and h for consonants, ng
+/// for ㅇ as a final, and a, ae, ya, yae, eo, e, yeo, ye, o, wa, wae, oe,
+/// yo, u, wo, we, wi, yu, eu, ui and i for vowels. As in the romanization,
+/// k, t and p after a vowel are the finals ㄱ, ㄷ and ㅂ unless a vowel
+/// follows, so 학교 is "hakgyo", and a vowel at the start of a syllable gets
+/// a silent ㅇ initial.
+///
+/// Letters that may still be the start of a longer spelling, such as the n
+/// of ng or the e of eo, are kept pending until the next key decides them,
+/// and the longest spelling wins. Spellings that depend on the next letter
+/// are kept pending too: ng before a vowel is ㄴ followed by the vowel's
+/// initial ㄱ, so "hangeul" is 한글, and ㅇ otherwise. Since the
+/// romanization follows pronunciation, a consonant before a vowel always
+/// starts the vowel's syllable, so "hangugeo" is 한구거. An apostrophe ends
+/// the pending spelling and starts a new syllable without being typed, so
+/// 한국어 is "hangug'eo" and 중앙 is "jung'ang". Letters without a spelling
+/// and other characters end the current word and are kept as typed.
+///
+/// Composed jamo are passed to a `HangulWordComposer`, so consonants move
+/// to the next syllable as they would on a Korean keyboard. Popping removes
+/// a pending letter if there is one, and otherwise the last jamo typed.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::romaja::RomajaComposer;
+///
+/// let mut composer = RomajaComposer::new();
+/// for c in "annyeo".chars() {
+///     composer.push_char(c).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "안녀");
+///
+/// // The pending n is shown as ㄴ until the g makes it ㅇ
+/// composer.push_char('n').unwrap();
+/// assert_eq!(composer.as_string().unwrap(), "안년");
+/// composer.push_char('g').unwrap();
+/// assert_eq!(composer.as_string().unwrap(), "안녕");
+///
+/// // The ng is still pending, so backspace removes the g alone
+/// assert_eq!(composer.pop().unwrap(), Some('g'));
+/// assert_eq!(composer.as_string().unwrap(), "안년");
+///
+/// for c in "g haseyo!".chars() {
+///     composer.push_char(c).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "안녕 하세요!");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RomajaComposer<R = StandardRules> {
+    completed: String,
+    current: HangulWordComposer<R>,
+    units: Vec<Unit>,
+    pending: String,
+}
+
+impl Default for RomajaComposer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RomajaComposer {
+    /// Creates a new, empty `RomajaComposer` using the `StandardRules`.
+    pub fn new() -> Self {
+        Self::with_rules(StandardRules)
+    }
+}
+
+impl<R: CompositionRules> RomajaComposer<R> {
+    /// Creates a new, empty `RomajaComposer` that combines jamo typed one
+    /// after another according to the given `CompositionRules`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::romaja::RomajaComposer;
+    /// use hangul_cd::rules::NoDoubleInitials;
+    ///
+    /// let mut composer = RomajaComposer::with_rules(NoDoubleInitials);
+    /// for c in "ggakka".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "\u{1100}가까");
+    /// ```
+    pub fn with_rules(rules: R) -> Self {
+        Self {
+            completed: String::new(),
+            current: HangulWordComposer::with_rules(rules),
+            units: Vec::new(),
+            pending: String::new(),
+        }
+    }
+
+    /// Pushes a typed character to the `RomajaComposer`. Letters are
+    /// matched case-insensitively against the romanized spellings, and are
+    /// composed as soon as no longer spelling can start with them.
+    pub fn push_char(&mut self, c: char) -> Result<(), WordError> {
+        let c = c.to_ascii_lowercase();
+        if !c.is_ascii_lowercase() {
+            self.resolve_pending(Some(c))?;
+            if c == SYLLABLE_BREAK {
+                self.units.push(Unit {
+                    kind: UnitKind::Break,
+                    output: c,
+                    letters: Vec::new(),
+                });
+            } else {
+                self.pass_through(c)?;
+            }
+            return Ok(());
+        }
+
+        self.pending.push(c);
+        if !is_spelling_prefix(&self.pending) {
+            self.pending.pop();
+            self.resolve_pending(Some(c))?;
+            self.pending.push(c);
+            if !is_spelling_prefix(&self.pending) {
+                self.pending.clear();
+                return self.pass_through(c);
+            }
+        }
+
+        let extendable = ROMAJA.iter().any(|(spelling, _)| {
+            spelling.len() > self.pending.len() && spelling.starts_with(&self.pending)
+        });
+        if !extendable && self.pending != "ng" {
+            self.resolve_pending(None)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the composed string, with any pending letters shown as the
+    /// jamo they would be composed to if no further letters were typed.
+    pub fn as_string(&self) -> Result<String, WordError> {
+        let mut composer = self.clone();
+        composer.resolve_pending(None)?;
+        let mut result = composer.completed;
+        result.push_str(&composer.current.as_string()?);
+        Ok(result)
+    }
+
+    /// Pops the last pending letter, or the last jamo or syllable break
+    /// typed in the current word, or the last character of the completed
+    /// text, and returns it. Jamo typed with several letters, such as ㅕ
+    /// for yeo, are removed whole, along with the silent ㅇ added before a
+    /// vowel.
+    pub fn pop(&mut self) -> Result<Option<char>, WordError> {
+        if let Some(c) = self.pending.pop() {
+            return Ok(Some(c));
+        }
+        match self.units.pop() {
+            Some(unit) => {
+                // A double consonant given to the vowel goes back to being
+                // typed freely, unless a syllable break made it an initial
+                let after_break = self.units.len() >= 2
+                    && self.units[self.units.len() - 2].kind == UnitKind::Break;
+                if let (UnitKind::Vowel, Some(prev)) = (&unit.kind, self.units.last_mut())
+                    && prev.kind == UnitKind::Consonant
+                    && !after_break
+                {
+                    prev.letters
+                        .iter_mut()
+                        .for_each(|(_, position)| *position = None);
+                }
+                self.replay()?;
+                Ok(Some(unit.output))
+            }
+            None => Ok(self.completed.pop()),
+        }
+    }
+
+    /// Composes the pending letters, taking the longest spelling each time
+    /// and keeping letters without a spelling as typed. `next` is the
+    /// character typed after the pending letters, if any, which decides
+    /// spellings such as ng and k that depend on whether a vowel follows.
+    fn resolve_pending(&mut self, next: Option<char>) -> Result<(), WordError> {
+        let mut rest = std::mem::take(&mut self.pending);
+        while !rest.is_empty() {
+            let longest = ROMAJA
+                .iter()
+                .filter(|(spelling, _)| rest.starts_with(spelling))
+                .max_by_key(|(spelling, _)| spelling.len());
+            match longest {
+                Some((spelling, jamo)) => {
+                    let following = rest[spelling.len()..].chars().next().or(next);
+                    let before_vowel = following.is_some_and(starts_vowel);
+                    let after_vowel = self
+                        .units
+                        .last()
+                        .is_some_and(|unit| unit.kind == UnitKind::Vowel);
+                    let final_jamo = FINALS
+                        .iter()
+                        .find(|(final_spelling, _)| final_spelling == spelling)
+                        .map(|(_, jamo)| *jamo);
+                    match (*spelling, final_jamo) {
+                        ("ng", _) if before_vowel => {
+                            self.push_jamo('ㄴ')?;
+                            self.push_jamo('ㄱ')?;
+                        }
+                        (_, Some(final_jamo)) if after_vowel && !before_vowel => {
+                            self.push_jamo(final_jamo)?
+                        }
+                        _ => self.push_jamo(*jamo)?,
+                    }
+                    rest.drain(..spelling.len());
+                }
+                None => {
+                    let c = rest.remove(0);
+                    self.pass_through(c)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_jamo(&mut self, c: char) -> Result<(), WordError> {
+        let jamo = Jamo::from_compatibility_jamo(c)?;
+        let kind = match jamo {
+            Jamo::Vowel(_) | Jamo::CompositeVowel(_) => UnitKind::Vowel,
+            _ => UnitKind::Consonant,
+        };
+        let after_break = self
+            .units
+            .last()
+            .is_some_and(|unit| unit.kind == UnitKind::Break);
+
+        let mut letters = Vec::new();
+        if kind == UnitKind::Vowel {
+            match self.units.last_mut() {
+                // A double consonant before a vowel is its initial as a
+                // whole, rather than a final that only gives up its last half
+                Some(Unit {
+                    kind: UnitKind::Consonant,
+                    letters: prev,
+                    ..
+                }) => {
+                    if let [(Jamo::CompositeConsonant(_), position)] = prev.as_mut_slice() {
+                        *position = Some(JamoPosition::Initial);
+                        self.replay()?;
+                    }
+                }
+                // A vowel that cannot take the previous consonant as its
+                // initial starts a syllable with a silent ㅇ
+                _ => letters.push((Jamo::from_compatibility_jamo('ㅇ')?, None)),
+            }
+        }
+        // A consonant after a syllable break starts a new syllable rather
+        // than joining the one before it, even one still without a vowel
+        let position =
+            (kind == UnitKind::Consonant && after_break).then_some(JamoPosition::Initial);
+        if position.is_some() && self.after_lone_consonant() {
+            self.end_word()?;
+        }
+        letters.push((jamo, position));
+
+        let mut first_in_word = 0;
+        for (i, (letter, position)) in letters.iter().enumerate() {
+            if self.push_letter(letter, position)? != WordPushResult::Continue {
+                // The letter cannot continue the word, so it starts a new one
+                self.end_word()?;
+                if self.push_letter(letter, position)? != WordPushResult::Continue {
+                    return self.pass_through(c);
+                }
+                first_in_word = i;
+            }
+        }
+        letters.drain(..first_in_word);
+        self.units.push(Unit {
+            kind,
+            output: c,
+            letters,
+        });
+        Ok(())
+    }
+
+    fn push_letter(
+        &mut self,
+        letter: &Jamo,
+        position: &Option<JamoPosition>,
+    ) -> Result<WordPushResult, WordError> {
+        match position {
+            Some(position) => self.current.push_positioned(letter, position.clone()),
+            None => self.current.push(letter),
+        }
+    }
+
+    /// Rebuilds the current word from the units typed in it.
+    fn replay(&mut self) -> Result<(), WordError> {
+        self.current = HangulWordComposer::with_rules(self.current.rules().clone());
+        for unit in self.units.clone() {
+            for (letter, position) in &unit.letters {
+                self.push_letter(letter, position)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn pass_through(&mut self, c: char) -> Result<(), WordError> {
+        self.end_word()?;
+        self.completed.push(c);
+        Ok(())
+    }
+
+    /// Returns whether the syllable being typed has consonants but no vowel.
+    fn after_lone_consonant(&self) -> bool {
+        let letters = self.current.cur_block().letters();
+        !letters.is_empty()
+            && !letters
+                .iter()
+                .any(|letter| matches!(letter, Jamo::Vowel(_) | Jamo::CompositeVowel(_)))
+    }
+
+    fn end_word(&mut self) -> Result<(), WordError> {
+        self.completed.push_str(&self.current.as_string()?);
+        self.current = HangulWordComposer::with_rules(self.current.rules().clone());
+        self.units.clear();
+        Ok(())
+    }
+}
+
+fn is_spelling_prefix(s: &str) -> bool {
+    ROMAJA.iter().any(|(spelling, _)| spelling.starts_with(s))
+}
+
+/// Returns whether a vowel spelling starts with the given letter.
+fn starts_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'w' | 'y')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_romaja(text: &str) -> String {
+        let mut composer = RomajaComposer::new();
+        for c in text.chars() {
+            composer.push_char(c).unwrap();
+        }
+        composer.as_string().unwrap()
+    }
+
+    #[test]
+    fn romaja_words() {
+        let tests = [
+            ("annyeonghaseyo", "안녕하세요"),
+            ("Han'gug'eo", "한국어"),
+            ("hangugeo", "한구거"),
+            ("hangug'eo", "한국어"),
+            ("han'geul", "한글"),
+            ("hangeul", "한글"),
+            ("jung'ang", "중앙"),
+            ("hakgyo", "학교"),
+            ("chaek", "책"),
+            ("bapgwa", "밥과"),
+            ("mitda", "믿다"),
+            ("keun", "큰"),
+            ("pado", "파도"),
+            ("hakip", "하킵"),
+            ("gwaja", "과자"),
+            ("uiuiui", "의의의"),
+            ("akka", "아까"),
+            ("ssaurm", "싸욺"),
+            ("mollayo", "몰라요"),
+            ("issda", "있다"),
+            ("saeroun", "새로운"),
+            ("oegug-eo", "외국-어"),
+            ("gamsahamnida!", "감사함니다!"),
+        ];
+        for (romaja, hangul) in tests {
+            assert_eq!(type_romaja(romaja), hangul, "{romaja}");
+        }
+    }
+
+    #[test]
+    fn live_display() {
+        let mut composer = RomajaComposer::new();
+        let mut shown = Vec::new();
+        for c in "gwae".chars() {
+            composer.push_char(c).unwrap();
+            shown.push(composer.as_string().unwrap());
+        }
+        assert_eq!(shown, ["\u{1100}", "\u{1100}w", "과", "괘"]);
+
+        // ng and k are shown as finals until a vowel makes them ㄴ+ㄱ and ㅋ
+        let mut composer = RomajaComposer::new();
+        let mut shown = Vec::new();
+        for c in "hangukin".chars() {
+            composer.push_char(c).unwrap();
+            shown.push(composer.as_string().unwrap());
+        }
+        assert_eq!(
+            shown,
+            [
+                "\u{1112}",
+                "하",
+                "한",
+                "항",
+                "한구",
+                "한국",
+                "한구키",
+                "한구킨"
+            ]
+        );
+    }
+
+    #[test]
+    fn syllable_breaks() {
+        assert_eq!(type_romaja("g'g"), "\u{1100}\u{1100}");
+        assert_eq!(type_romaja("g'ga"), "\u{1100}가");
+        assert_eq!(type_romaja("gag'ga"), "각가");
+        assert_eq!(type_romaja("dal'gi"), "달기");
+        assert_eq!(type_romaja("ak'ka"), "악카");
+
+        // A break keeps a double consonant as an initial after backspace
+        let mut composer = RomajaComposer::new();
+        for c in "a'kka".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "아까");
+        composer.pop().unwrap();
+        assert_eq!(composer.as_string().unwrap(), "아\u{1101}");
+    }
+
+    #[test]
+    fn composition_rules() {
+        let mut composer = RomajaComposer::with_rules(NoDoubleInitials);
+        for c in "gga".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "\u{1100}가");
+
+        let mut composer = RomajaComposer::with_rules(ExtendedClusters);
+        for c in "dasg isgo".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "닧 익소");
+    }
+
+    #[test]
+    fn unknown_letters() {
+        assert_eq!(type_romaja("fan"), "f안");
+        assert_eq!(type_romaja("cha x"), "차 x");
+        assert_eq!(type_romaja("ca"), "c아");
+    }
+
+    #[test]
+    fn backspace() {
+        let mut composer = RomajaComposer::new();
+        for c in "ye ye".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "예 예");
+        assert_eq!(composer.pop().unwrap(), Some('e'));
+        assert_eq!(composer.as_string().unwrap(), "예 y");
+        assert_eq!(composer.pop().unwrap(), Some('y'));
+        assert_eq!(composer.pop().unwrap(), Some(' '));
+        assert_eq!(composer.pop().unwrap(), Some('예'));
+        assert_eq!(composer.as_string().unwrap(), "");
+
+        for c in "dal'gi".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.pop().unwrap(), Some('ㅣ'));
+        assert_eq!(composer.pop().unwrap(), Some('ㄱ'));
+        assert_eq!(composer.pop().unwrap(), Some('\''));
+        assert_eq!(composer.as_string().unwrap(), "달");
+        composer.push_char('a').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "다라");
+
+        let mut composer = RomajaComposer::new();
+        for c in "akkan".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "아깐");
+        assert_eq!(composer.pop().unwrap(), Some('n'));
+        assert_eq!(composer.pop().unwrap(), Some('ㅏ'));
+        assert_eq!(composer.as_string().unwrap(), "앆");
+    }
+}
@@ -0,0 +1,233 @@
+use crate::jamo::*;
+
+/// Rules deciding which pairs of jamo typed one after another combine into a
+/// double initial, composite vowel or composite final while composing. The
+/// composers consult their rules whenever a second jamo is typed into a
+/// slot, so IME variants can change what combines without changing the
+/// composition state machine.
+///
+/// Every method defaults to the standard behaviour, so an implementation
+/// only needs to override the slots it changes. Rules only govern jamo
+/// typed separately; a double or composite jamo pushed as a single letter,
+/// such as ㄲ typed with Shift on 2-beolsik, is placed as usual.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::jamo::{JamoConsonantComposite, JamoConsonantSingular};
+/// use hangul_cd::rules::CompositionRules;
+/// use hangul_cd::word::HangulWordComposer;
+///
+/// // Combines ㄹ+ㄱ into ㄺ, but no other final clusters
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// struct OnlyRieulGiyeok;
+///
+/// impl CompositionRules for OnlyRieulGiyeok {
+///     fn combine_final(
+///         &self,
+///         first: &JamoConsonantSingular,
+///         second: &JamoConsonantSingular,
+///     ) -> Option<JamoConsonantComposite> {
+///         match (first, second) {
+///             (JamoConsonantSingular::Rieul, JamoConsonantSingular::Giyeok) => {
+///                 Some(JamoConsonantComposite::RieulGiyeok)
+///             }
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let mut composer = HangulWordComposer::with_rules(OnlyRieulGiyeok);
+/// for c in "ㄷㅏㄹㄱㄱㅏㅂㅅ".chars() {
+///     composer.push_char(c).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "닭갑ᄉ");
+/// ```
+pub trait CompositionRules: Clone {
+    /// Returns the double initial that two consonants typed in the initial
+    /// slot combine into, or `None` if they do not combine.
+    fn combine_initial(
+        &self,
+        first: &JamoConsonantSingular,
+        second: &JamoConsonantSingular,
+    ) -> Option<JamoConsonantComposite> {
+        first.combine_for_initial(second)
+    }
+
+    /// Returns the composite vowel that two vowels typed in the vowel slot
+    /// combine into, or `None` if they do not combine.
+    fn combine_vowel(
+        &self,
+        first: &JamoVowelSingular,
+        second: &JamoVowelSingular,
+    ) -> Option<JamoVowelComposite> {
+        first.combine(second)
+    }
+
+    /// Returns the composite final that two consonants typed in the final
+    /// slot combine into, or `None` if the second starts a new syllable.
+    fn combine_final(
+        &self,
+        first: &JamoConsonantSingular,
+        second: &JamoConsonantSingular,
+    ) -> Option<JamoConsonantComposite> {
+        first.combine_for_final(second)
+    }
+}
+
+/// The standard composition rules, as on a 2-beolsik keyboard: the same
+/// consonant typed twice as an initial makes a double consonant such as ㄲ,
+/// ㅗ, ㅜ and ㅡ combine with a following vowel into ㅘ, ㅝ, ㅢ and the
+/// like, and consonants combine into the eleven final clusters and the
+/// double finals ㄲ and ㅆ. Composers use these rules unless given others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StandardRules;
+
+impl CompositionRules for StandardRules {}
+
+/// The standard rules without double initials, for layouts with separate
+/// keys for ㄲ, ㄸ, ㅃ, ㅆ and ㅉ, so that typing ㄱ twice leaves two ㄱ
+/// instead of making ㄲ.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{BlockComposer, BlockPushResult};
+/// use hangul_cd::jamo::Jamo;
+/// use hangul_cd::rules::NoDoubleInitials;
+///
+/// let giyeok = Jamo::from_compatibility_jamo('ㄱ').unwrap();
+///
+/// let mut composer = BlockComposer::new();
+/// composer.push(&giyeok);
+/// assert_eq!(composer.push(&giyeok), BlockPushResult::Success);
+/// assert_eq!(composer.block_as_string().unwrap(), Some('ᄁ'));
+///
+/// let mut composer = BlockComposer::with_rules(NoDoubleInitials);
+/// composer.push(&giyeok);
+/// assert_eq!(composer.push(&giyeok), BlockPushResult::InvalidHangul);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoDoubleInitials;
+
+impl CompositionRules for NoDoubleInitials {
+    fn combine_initial(
+        &self,
+        _first: &JamoConsonantSingular,
+        _second: &JamoConsonantSingular,
+    ) -> Option<JamoConsonantComposite> {
+        None
+    }
+}
+
+/// The standard rules without composite vowels, for layouts with a
+/// separate key for every composite vowel, so that ㅗ followed by ㅏ stays
+/// two vowels instead of making ㅘ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoCompositeVowels;
+
+impl CompositionRules for NoCompositeVowels {
+    fn combine_vowel(
+        &self,
+        _first: &JamoVowelSingular,
+        _second: &JamoVowelSingular,
+    ) -> Option<JamoVowelComposite> {
+        None
+    }
+}
+
+/// The standard rules with final clusters also accepted in reverse order,
+/// so that ㅅ followed by ㄱ makes ㄳ and ㅎ followed by ㄹ makes ㅀ, for
+/// typists who press the keys of a cluster out of order. A cluster typed in
+/// reverse is kept as if typed in the standard order, so popping ㄳ leaves
+/// ㄱ and a following vowel takes the ㅅ, as shown on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExtendedClusters;
+
+impl CompositionRules for ExtendedClusters {
+    fn combine_final(
+        &self,
+        first: &JamoConsonantSingular,
+        second: &JamoConsonantSingular,
+    ) -> Option<JamoConsonantComposite> {
+        first
+            .combine_for_final(second)
+            .or_else(|| second.combine_for_final(first))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::{HangulWordComposer, WordPushResult};
+
+    fn compose<R: CompositionRules>(rules: R, input: &str) -> HangulWordComposer<R> {
+        let mut composer = HangulWordComposer::with_rules(rules);
+        for c in input.chars() {
+            composer.push_char(c).unwrap();
+        }
+        composer
+    }
+
+    #[test]
+    fn standard_rules_unchanged() {
+        let input = "ㄲㅗㅊㄱㅘㅇㄷㅏㄹㄱㄲㅏㄱㄱ";
+        let standard = compose(StandardRules, input).as_string().unwrap();
+        let default = {
+            let mut composer = HangulWordComposer::new();
+            for c in input.chars() {
+                composer.push_char(c).unwrap();
+            }
+            composer.as_string().unwrap()
+        };
+        assert_eq!(standard, "꽃광닭깎");
+        assert_eq!(standard, default);
+    }
+
+    #[test]
+    fn rules_apply_across_blocks() {
+        let composer = compose(ExtendedClusters, "ㄷㅏㄷㅏㅅㄱㅇㅣㅎㄹ");
+        assert_eq!(composer.as_string().unwrap(), "다닧잃");
+        let composer = compose(StandardRules, "ㄷㅏㄷㅏㅅㄱ");
+        assert_eq!(composer.as_string().unwrap(), "다닷\u{1100}");
+
+        // A block reopened by popping keeps the rules
+        let mut composer = compose(ExtendedClusters, "ㄷㅏㅅㅇㅏ");
+        assert_eq!(composer.as_string().unwrap(), "닷아");
+        composer.pop().unwrap();
+        composer.pop().unwrap();
+        composer.push_char('ㄱ').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "닧");
+    }
+
+    #[test]
+    fn reversed_pairs_stored_in_standard_order() {
+        // ㅅ then ㄱ is kept as ㄳ, so the ㅅ is popped or moved first
+        let mut composer = compose(ExtendedClusters, "ㄷㅏㅅㄱ");
+        assert_eq!(composer.as_string().unwrap(), "닧");
+        assert_eq!(
+            composer.pop().unwrap(),
+            Some(Jamo::Consonant(JamoConsonantSingular::Siot))
+        );
+        assert_eq!(composer.as_string().unwrap(), "닥");
+
+        let composer = compose(ExtendedClusters, "ㄷㅏㅅㄱㅏ");
+        assert_eq!(composer.as_string().unwrap(), "닥사");
+        let composer = compose(ExtendedClusters, "ㅇㅣㅎㄹㅓ");
+        assert_eq!(composer.as_string().unwrap(), "일허");
+    }
+
+    #[test]
+    fn disabled_combinations() {
+        let mut composer = compose(NoCompositeVowels, "ㄱㅗ");
+        assert_eq!(composer.push_char('ㅏ'), Ok(WordPushResult::InvalidHangul));
+        assert_eq!(composer.push_char('ㅘ'), Ok(WordPushResult::InvalidHangul));
+        assert_eq!(
+            compose(NoCompositeVowels, "ㄱㅘ").as_string().unwrap(),
+            "과"
+        );
+
+        let mut composer = compose(NoDoubleInitials, "ㄱ");
+        assert_eq!(composer.push_char('ㄱ'), Ok(WordPushResult::InvalidHangul));
+        let composer = compose(NoDoubleInitials, "ㅇㅏㄱㄱㄲㅏ");
+        assert_eq!(composer.as_string().unwrap(), "앆까");
+    }
+}
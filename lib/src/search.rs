@@ -0,0 +1,705 @@
+use std::ops::Range;
+
+use crate::{block::*, jamo::*, rules::*, word::*};
+
+/// Returns the initial consonant (초성) of a character. For a composed Hangul
+/// syllable this is the initial of its block; for a consonant jamo it is the
+/// jamo itself. Returns `None` for any other character.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::search::choseong;
+/// use hangul_cd::jamo::{Jamo, JamoConsonantSingular};
+///
+/// assert_eq!(choseong('한'), Some(Jamo::Consonant(JamoConsonantSingular::Hieut)));
+/// assert_eq!(choseong('ㄱ'), Some(Jamo::Consonant(JamoConsonantSingular::Giyeok)));
+/// assert_eq!(choseong('A'), None);
+/// ```
+pub fn choseong(c: char) -> Option<Jamo> {
+    if let Ok(block) = HangulBlock::from_char(c) {
+        return Some(block.initial);
+    }
+    match Character::from_char(c) {
+        Ok(Character::Hangul(jamo @ (Jamo::Consonant(_) | Jamo::CompositeConsonant(_)))) => {
+            Some(jamo)
+        }
+        _ => None,
+    }
+}
+
+/// Replaces every Hangul syllable in a string with its initial consonant
+/// (초성) as a compatibility jamo. All other characters are kept unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::search::extract_choseong;
+///
+/// assert_eq!(extract_choseong("한글"), "ㅎㄱ");
+/// assert_eq!(extract_choseong("대한민국 2026!"), "ㄷㅎㅁㄱ 2026!");
+/// ```
+pub fn extract_choseong(s: &str) -> String {
+    s.chars()
+        .map(|c| match HangulBlock::from_char(c) {
+            Ok(block) => block.initial.char_compatibility(),
+            Err(_) => c,
+        })
+        .collect()
+}
+
+/// Options for matching queries with a `ChoseongMatcher`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChoseongMatchOptions {
+    /// Whether non-Hangul characters are compared case-insensitively.
+    pub ignore_case: bool,
+
+    /// Whether whitespace is ignored in both the query and the candidate,
+    /// so that ㅎㄱㄷ matches 홍 길동.
+    pub ignore_whitespace: bool,
+}
+
+impl Default for ChoseongMatchOptions {
+    fn default() -> Self {
+        ChoseongMatchOptions {
+            ignore_case: true,
+            ignore_whitespace: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum QueryUnit {
+    /// A lone consonant; matches any syllable with this initial.
+    Initial(Jamo),
+
+    /// Any other character, including composed syllables; matches itself.
+    Literal(char),
+}
+
+/// A matcher for search queries that mix initial consonants (초성), full
+/// syllables and other text, as typed into contact lists and menus.
+///
+/// Each consonant in the query matches a syllable starting with that
+/// consonant (or the bare consonant itself), while every other query
+/// character must match exactly. Matches are contiguous and reported as
+/// ranges of char indices into the candidate.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::search::{ChoseongMatcher, ChoseongMatchOptions};
+///
+/// let matcher = ChoseongMatcher::new("ㅎㄱ", ChoseongMatchOptions::default());
+/// assert!(matcher.is_match("한글"));
+/// assert_eq!(matcher.find("대한국인"), Some(1..3));
+///
+/// // Queries can mix initials, syllables and Latin text
+/// let matcher = ChoseongMatcher::new("ㅋ피", ChoseongMatchOptions::default());
+/// assert_eq!(matcher.find("아이스 커피"), Some(4..6));
+///
+/// let matcher = ChoseongMatcher::new("BTSㅈ", ChoseongMatchOptions::default());
+/// assert!(matcher.is_match("bts 정국"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChoseongMatcher {
+    query: Vec<QueryUnit>,
+    options: ChoseongMatchOptions,
+}
+
+impl ChoseongMatcher {
+    /// Creates a new `ChoseongMatcher` for the given query.
+    pub fn new(query: &str, options: ChoseongMatchOptions) -> Self {
+        let query = query
+            .chars()
+            .filter(|c| !(options.ignore_whitespace && c.is_whitespace()))
+            .map(|c| match Character::from_char(c) {
+                Ok(Character::Hangul(
+                    jamo @ (Jamo::Consonant(_) | Jamo::CompositeConsonant(_)),
+                )) => QueryUnit::Initial(jamo),
+                _ => QueryUnit::Literal(c),
+            })
+            .collect();
+        ChoseongMatcher { query, options }
+    }
+
+    /// Returns `true` if the query matches anywhere in the candidate.
+    pub fn is_match(&self, candidate: &str) -> bool {
+        self.find(candidate).is_some()
+    }
+
+    /// Returns the char range of the first match in the candidate, if any.
+    /// An empty query matches at the start of any candidate.
+    pub fn find(&self, candidate: &str) -> Option<Range<usize>> {
+        let chars: Vec<char> = candidate.chars().collect();
+        (0..=chars.len()).find_map(|start| self.match_at(&chars, start))
+    }
+
+    /// Returns the char ranges of all non-overlapping matches in the
+    /// candidate, in order.
+    pub fn find_all(&self, candidate: &str) -> Vec<Range<usize>> {
+        let chars: Vec<char> = candidate.chars().collect();
+        let mut result = Vec::new();
+        if self.query.is_empty() {
+            return result;
+        }
+        let mut start = 0;
+        while start < chars.len() {
+            match self.match_at(&chars, start) {
+                Some(range) => {
+                    start = range.end;
+                    result.push(range);
+                }
+                None => start += 1,
+            }
+        }
+        result
+    }
+
+    fn match_at(&self, chars: &[char], start: usize) -> Option<Range<usize>> {
+        if start < chars.len() && self.is_skipped(chars[start]) {
+            return None;
+        }
+        let mut pos = start;
+        for unit in &self.query {
+            while pos < chars.len() && self.is_skipped(chars[pos]) {
+                pos += 1;
+            }
+            if pos >= chars.len() || !self.unit_matches(unit, chars[pos]) {
+                return None;
+            }
+            pos += 1;
+        }
+        Some(start..pos)
+    }
+
+    fn is_skipped(&self, c: char) -> bool {
+        self.options.ignore_whitespace && c.is_whitespace()
+    }
+
+    fn unit_matches(&self, unit: &QueryUnit, c: char) -> bool {
+        match unit {
+            QueryUnit::Initial(jamo) => choseong(c).as_ref() == Some(jamo),
+            QueryUnit::Literal(q) if self.options.ignore_case => {
+                q.to_lowercase().eq(c.to_lowercase())
+            }
+            QueryUnit::Literal(q) => *q == c,
+        }
+    }
+}
+
+/// Splits a character into the jamo letters that would be typed to produce
+/// it: syllables are split into their initial, vowel and final, and double
+/// or composite jamo are split into their parts. Returns `None` for
+/// non-Hangul characters.
+pub(crate) fn typed_letters(c: char) -> Option<Vec<Jamo>> {
+    if let Ok(block) = HangulBlock::from_char(c) {
+        let (i1, i2, v1, v2, f1, f2) = block.decomposed_tuple().ok()?;
+        return Some([i1, i2, v1, v2, f1, f2].into_iter().flatten().collect());
+    }
+    match Character::from_char(c).ok()? {
+        Character::Hangul(Jamo::CompositeConsonant(composite)) => {
+            let (a, b) = composite.decompose();
+            Some(vec![a, b])
+        }
+        Character::Hangul(Jamo::CompositeVowel(composite)) => {
+            let (a, b) = composite.decompose();
+            Some(vec![a, b])
+        }
+        Character::Hangul(jamo) => Some(vec![jamo]),
+        Character::NonHangul(_) => None,
+    }
+}
+
+/// A prefix matcher for autocomplete while the query is still being typed.
+///
+/// The last syllable of the query is treated as unfinished and compared
+/// letter by letter against the candidate, so a query matches every
+/// candidate the user could still be on their way to typing. "하" matches
+/// 한, 할 and 학, and "갑" matches "가바" because the ㅂ may still move to the
+/// next syllable once a vowel is typed. Only such a trailing final can move;
+/// initials and vowels must match within a single syllable, and every
+/// character before the last syllable must match exactly.
+///
+/// Double consonants and composite jamo are compared as the two letters
+/// they are typed with, so ㄱ also matches 까 and 고 also matches 과.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::search::IncrementalMatcher;
+///
+/// let matcher = IncrementalMatcher::new("하");
+/// assert!(matcher.is_match("한국"));
+/// assert!(matcher.is_match("학교"));
+/// assert!(!matcher.is_match("허리"));
+///
+/// // The trailing consonant may belong to the next syllable
+/// let matcher = IncrementalMatcher::new("갑");
+/// assert!(matcher.is_match("가방"));
+/// assert_eq!(matcher.match_len("가방"), Some(2));
+///
+/// // Earlier syllables must match exactly
+/// let matcher = IncrementalMatcher::new("대하");
+/// assert!(matcher.is_match("대한민국"));
+/// assert!(!matcher.is_match("댄하"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncrementalMatcher {
+    completed: Vec<char>,
+    pending: Vec<Jamo>,
+}
+
+impl IncrementalMatcher {
+    /// Creates a new `IncrementalMatcher` from the text of a query, treating
+    /// its last character as the syllable being typed if it is Hangul.
+    pub fn new(query: &str) -> Self {
+        let mut completed: Vec<char> = query.chars().collect();
+        let pending = match completed.last().and_then(|c| typed_letters(*c)) {
+            Some(letters) => {
+                completed.pop();
+                letters
+            }
+            None => Vec::new(),
+        };
+        IncrementalMatcher { completed, pending }
+    }
+
+    /// Creates a new `IncrementalMatcher` from the state of a
+    /// `HangulWordComposer`, using its current block as the syllable being
+    /// typed. Returns an error if a completed block is not a valid syllable.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::search::IncrementalMatcher;
+    /// use hangul_cd::word::HangulWordComposer;
+    ///
+    /// let mut composer = HangulWordComposer::new();
+    /// for c in ['ㅎ', 'ㅏ', 'ㄴ', 'ㄱ'] {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// let matcher = IncrementalMatcher::from_word_composer(&composer).unwrap();
+    /// assert!(matcher.is_match("한글"));
+    /// assert!(!matcher.is_match("한라산"));
+    /// ```
+    pub fn from_word_composer<R: CompositionRules>(
+        composer: &HangulWordComposer<R>,
+    ) -> Result<Self, WordError> {
+        let completed = hangul_blocks_vec_to_string(composer.prev_blocks())?
+            .chars()
+            .collect();
+        Ok(IncrementalMatcher {
+            completed,
+            pending: composer.cur_block().letters(),
+        })
+    }
+
+    /// Returns `true` if the candidate could be the result of continuing to
+    /// type the query.
+    pub fn is_match(&self, candidate: &str) -> bool {
+        self.match_len(candidate).is_some()
+    }
+
+    /// Returns the number of chars at the start of the candidate covered by
+    /// the query, or `None` if the candidate does not match. The syllable
+    /// being typed must match within one syllable of the candidate, except
+    /// that the last letter of its final may move to the initial of the
+    /// next syllable, which then counts as covered.
+    pub fn match_len(&self, candidate: &str) -> Option<usize> {
+        let chars: Vec<char> = candidate.chars().collect();
+        if !chars.starts_with(&self.completed) {
+            return None;
+        }
+        let pos = self.completed.len();
+        if self.pending.is_empty() {
+            return Some(pos);
+        }
+        let letters = chars.get(pos).and_then(|c| typed_letters(*c))?;
+        if letters.starts_with(&self.pending) {
+            return Some(pos + 1);
+        }
+
+        // A trailing final consonant may still start the next syllable
+        let (last, rest) = self.pending.split_last()?;
+        let has_vowel = rest
+            .iter()
+            .any(|jamo| matches!(jamo, Jamo::Vowel(_) | Jamo::CompositeVowel(_)));
+        let next = chars
+            .get(pos + 1)
+            .and_then(|c| HangulBlock::from_char(*c).ok());
+        match (next, last) {
+            (Some(next), Jamo::Consonant(_))
+                if has_vowel
+                    && letters == rest
+                    && typed_letters(next.to_char().ok()?)?.first() == Some(last) =>
+            {
+                Some(pos + 2)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A range of strings that share a fixed prefix followed by one char from
+/// an inclusive range, as produced by `syllable_prefix_ranges`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyllableRange {
+    /// The text every string in the range starts with.
+    pub prefix: String,
+
+    /// The lowest char that can follow the prefix.
+    pub first: char,
+
+    /// The highest char that can follow the prefix.
+    pub last: char,
+}
+
+impl SyllableRange {
+    /// Returns the inclusive lower bound of the range, for use as
+    /// `column >= lower_bound` in a database query.
+    pub fn lower_bound(&self) -> String {
+        format!("{}{}", self.prefix, self.first)
+    }
+
+    /// Returns the exclusive upper bound of the range, for use as
+    /// `column < upper_bound` in a database query. Returns `None` if the
+    /// range has no upper bound.
+    pub fn upper_bound(&self) -> Option<String> {
+        let next = (self.last as u32 + 1..=char::MAX as u32).find_map(char::from_u32)?;
+        Some(format!("{}{}", self.prefix, next))
+    }
+
+    /// Returns `true` if the string falls in the range.
+    pub fn contains(&self, s: &str) -> bool {
+        s.strip_prefix(&self.prefix)
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| (self.first..=self.last).contains(&c))
+    }
+}
+
+/// Returns the codepoint ranges covering every string that could result from
+/// continuing to type the query, for prefix searches in a database. As with
+/// `IncrementalMatcher`, every character before the last syllable must
+/// match exactly, and the last syllable is matched jamo by jamo.
+///
+/// When the query ends in a final consonant, the last letter of the final
+/// may still move to the next syllable once a vowel is typed, so the result
+/// also includes a range of the syllables following the open syllable; "갑"
+/// gives 갑 and 값 as well as 가 followed by a syllable starting with ㅂ or
+/// ㅃ, and "닭" gives 닭 as well as 달 followed by one starting with ㄱ or ㄲ. A query ending
+/// in something other than Hangul gives a single range for its last char.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::search::syllable_prefix_ranges;
+///
+/// let ranges = syllable_prefix_ranges("하");
+/// assert_eq!(ranges.len(), 1);
+/// assert_eq!((ranges[0].first, ranges[0].last), ('하', '핳'));
+///
+/// let ranges = syllable_prefix_ranges("갑");
+/// assert_eq!(ranges.len(), 2);
+/// assert_eq!((ranges[0].first, ranges[0].last), ('갑', '값'));
+/// assert_eq!(ranges[1].lower_bound(), "가바");
+/// assert_eq!(ranges[1].upper_bound(), Some("가사".to_string()));
+/// assert!(ranges[1].contains("가방"));
+/// ```
+pub fn syllable_prefix_ranges(query: &str) -> Vec<SyllableRange> {
+    let matcher = IncrementalMatcher::new(query);
+    let mut prefix: String = matcher.completed.iter().collect();
+    if matcher.pending.is_empty() {
+        return match prefix.pop() {
+            Some(c) => vec![SyllableRange {
+                prefix,
+                first: c,
+                last: c,
+            }],
+            None => vec![SyllableRange {
+                prefix,
+                first: '\0',
+                last: char::MAX,
+            }],
+        };
+    }
+
+    let letters = &matcher.pending;
+    let consonants = letters.iter().take_while(|l| is_consonant(l)).count();
+    let vowels = letters[consonants..]
+        .iter()
+        .take_while(|l| !is_consonant(l))
+        .count();
+    let (initial, rest) = letters.split_at(consonants);
+    let (vowel, finals) = rest.split_at(vowels);
+
+    if vowel.is_empty() {
+        return match initials_starting_with(initial) {
+            Some(range) => vec![syllables_with_initials(prefix, range)],
+            None => Vec::new(),
+        };
+    }
+    let l_index = match slot_index(initial, L_BASE, 0..L_COUNT) {
+        Some(l_index) => l_index,
+        None => return Vec::new(),
+    };
+    if finals.is_empty() {
+        return match slot_indices_starting_with(vowel, V_BASE, 0..V_COUNT) {
+            Some((v_first, v_last)) => vec![SyllableRange {
+                prefix,
+                first: syllable(l_index, v_first, 0),
+                last: syllable(l_index, v_last, T_COUNT - 1),
+            }],
+            None => Vec::new(),
+        };
+    }
+    let v_index = match slot_index(vowel, V_BASE, 0..V_COUNT) {
+        Some(v_index) => v_index,
+        None => return Vec::new(),
+    };
+
+    let mut result = Vec::new();
+    // The finals typed so far all stay in the open syllable
+    if let Some((t_first, t_last)) = slot_indices_starting_with(finals, T_BASE, 1..T_COUNT) {
+        result.push(SyllableRange {
+            prefix: prefix.clone(),
+            first: syllable(l_index, v_index, t_first),
+            last: syllable(l_index, v_index, t_last),
+        });
+    }
+    // Or the last of them moves to the next syllable's initial
+    let (kept, moved) = finals.split_at(finals.len() - 1);
+    let t_index = match kept {
+        [] => Some(0),
+        kept => slot_index(kept, T_BASE, 1..T_COUNT),
+    };
+    if let (Some(t_index), Some(range)) = (t_index, initials_starting_with(moved)) {
+        prefix.push(syllable(l_index, v_index, t_index));
+        result.push(syllables_with_initials(prefix, range));
+    }
+    result
+}
+
+fn is_consonant(jamo: &Jamo) -> bool {
+    matches!(jamo, Jamo::Consonant(_) | Jamo::CompositeConsonant(_))
+}
+
+fn syllable(l_index: u32, v_index: u32, t_index: u32) -> char {
+    char::from_u32(S_BASE + l_index * N_COUNT + v_index * T_COUNT + t_index).unwrap_or('\0')
+}
+
+fn slot_letters(base: u32, index: u32) -> Option<Vec<Jamo>> {
+    typed_letters(char::from_u32(base + index)?)
+}
+
+/// Returns the index of the jamo in a slot typed with exactly `letters`.
+fn slot_index(letters: &[Jamo], base: u32, indices: Range<u32>) -> Option<u32> {
+    indices
+        .into_iter()
+        .find(|i| slot_letters(base, *i).is_some_and(|l| l == letters))
+}
+
+/// Returns the lowest and highest index of the jamo in a slot whose letters
+/// start with `letters`. In Unicode order, the jamo sharing a first letter
+/// are always adjacent (ㅗ, ㅘ, ㅙ, ㅚ or ㄹ, ㄺ, … ㅀ), so the indices in
+/// between match as well.
+fn slot_indices_starting_with(
+    letters: &[Jamo],
+    base: u32,
+    indices: Range<u32>,
+) -> Option<(u32, u32)> {
+    let mut matching = indices
+        .into_iter()
+        .filter(|i| slot_letters(base, *i).is_some_and(|l| l.starts_with(letters)));
+    let first = matching.next()?;
+    Some((first, matching.next_back().unwrap_or(first)))
+}
+
+fn initials_starting_with(letters: &[Jamo]) -> Option<(u32, u32)> {
+    slot_indices_starting_with(letters, L_BASE, 0..L_COUNT)
+}
+
+fn syllables_with_initials(prefix: String, (l_first, l_last): (u32, u32)) -> SyllableRange {
+    SyllableRange {
+        prefix,
+        first: syllable(l_first, 0, 0),
+        last: syllable(l_last, V_COUNT - 1, T_COUNT - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_choseong_mixed() {
+        let tests = vec![
+            ("", ""),
+            ("까치", "ㄲㅊ"),
+            ("abc", "abc"),
+            ("ㄱ가", "ㄱㄱ"),
+            ("서울시 강남구", "ㅅㅇㅅ ㄱㄴㄱ"),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(extract_choseong(input), expected);
+        }
+    }
+
+    #[test]
+    fn initials_match_syllables_and_jamo() {
+        let matcher = ChoseongMatcher::new("ㄲㅊ", ChoseongMatchOptions::default());
+        assert_eq!(matcher.find("까치"), Some(0..2));
+        assert_eq!(matcher.find("ㄲ치"), Some(0..2));
+        assert_eq!(matcher.find("가치"), None);
+    }
+
+    #[test]
+    fn whitespace_handling() {
+        let matcher = ChoseongMatcher::new("ㅎㄱㄷ", ChoseongMatchOptions::default());
+        assert_eq!(matcher.find("홍 길동"), Some(0..4));
+        assert_eq!(matcher.find("  홍길동"), Some(2..5));
+
+        let strict = ChoseongMatcher::new(
+            "ㅎㄱㄷ",
+            ChoseongMatchOptions {
+                ignore_case: true,
+                ignore_whitespace: false,
+            },
+        );
+        assert_eq!(strict.find("홍 길동"), None);
+        assert_eq!(strict.find("홍길동"), Some(0..3));
+    }
+
+    #[test]
+    fn case_sensitivity() {
+        let matcher = ChoseongMatcher::new(
+            "Kㅍ",
+            ChoseongMatchOptions {
+                ignore_case: false,
+                ignore_whitespace: true,
+            },
+        );
+        assert!(matcher.is_match("K팝"));
+        assert!(!matcher.is_match("k팝"));
+    }
+
+    #[test]
+    fn find_all_matches() {
+        let matcher = ChoseongMatcher::new("ㄱ", ChoseongMatchOptions::default());
+        assert_eq!(matcher.find_all("가나고"), vec![0..1, 2..3]);
+
+        let empty = ChoseongMatcher::new("", ChoseongMatchOptions::default());
+        assert_eq!(empty.find("아무거나"), Some(0..0));
+        assert!(empty.find_all("아무거나").is_empty());
+    }
+
+    #[test]
+    fn incremental_trailing_syllable() {
+        let tests = vec![
+            ("하", "한", Some(1)),
+            ("하", "할머니", Some(1)),
+            ("하", "화", None),
+            ("호", "화", Some(1)),
+            ("ㄱ", "까치", Some(1)),
+            ("갑", "갑니다", Some(1)),
+            ("갑", "가방", Some(2)),
+            ("갑", "가마", None),
+            ("닭", "달걀", Some(2)),
+            ("닭", "달", None),
+            ("닭", "달가", Some(2)),
+            ("닭", "달ㄱ", None),
+            ("까", "ㄱ가", None),
+            ("가", "ㄱㅏ", None),
+            ("과", "고아", None),
+            ("ㄱ", "ㄱ", Some(1)),
+            ("서울", "서울역", Some(2)),
+            ("서울 ", "서울역", None),
+            ("", "아무거나", Some(0)),
+        ];
+        for (query, candidate, expected) in tests {
+            let matcher = IncrementalMatcher::new(query);
+            assert_eq!(
+                matcher.match_len(candidate),
+                expected,
+                "{query} / {candidate}"
+            );
+        }
+    }
+
+    #[test]
+    fn incremental_matches_every_keystroke() {
+        let mut composer = HangulWordComposer::new();
+        for c in "ㄷㅐㅎㅏㄴㅁㅣㄴㄱㅜㄱ".chars() {
+            composer.push_char(c).unwrap();
+            let matcher = IncrementalMatcher::from_word_composer(&composer).unwrap();
+            assert!(
+                matcher.is_match("대한민국"),
+                "{}",
+                composer.as_string().unwrap()
+            );
+            assert_eq!(
+                matcher,
+                IncrementalMatcher::new(&composer.as_string().unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn incremental_matcher_from_composer_with_rules() {
+        let mut composer = HangulWordComposer::with_rules(ExtendedClusters);
+        for c in "ㄷㅏㅅㄱ".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "닧");
+        let matcher = IncrementalMatcher::from_word_composer(&composer).unwrap();
+        assert_eq!(
+            matcher,
+            IncrementalMatcher::new(&composer.as_string().unwrap())
+        );
+        assert!(matcher.is_match("닧"));
+        assert!(matcher.is_match("닥소"));
+        assert!(!matcher.is_match("닷고"));
+    }
+
+    #[test]
+    fn prefix_ranges_agree_with_incremental_matcher() {
+        let next_syllables: Vec<char> = (0..L_COUNT).map(|l| syllable(l, 0, 0)).collect();
+        for query in [
+            "하", "학", "갑", "핛", "닭", "핬", "호", "ㄱ", "ㄲ", "고기", "값",
+        ] {
+            let ranges = syllable_prefix_ranges(query);
+            let matcher = IncrementalMatcher::new(query);
+            let first = matcher.completed.len();
+            let candidates = (0..S_COUNT)
+                .filter_map(|i| char::from_u32(S_BASE + i))
+                .filter(|c| choseong(*c) == choseong(query.chars().nth(first).unwrap()));
+            for c in candidates {
+                for next in &next_syllables {
+                    let candidate: String = matcher.completed.iter().chain([&c, next]).collect();
+                    assert_eq!(
+                        ranges.iter().any(|r| r.contains(&candidate)),
+                        matcher.is_match(&candidate),
+                        "{query} / {candidate}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn prefix_ranges_for_other_queries() {
+        assert_eq!(
+            syllable_prefix_ranges("abc"),
+            vec![SyllableRange {
+                prefix: "ab".to_string(),
+                first: 'c',
+                last: 'c',
+            }]
+        );
+        assert!(
+            syllable_prefix_ranges("")
+                .iter()
+                .all(|r| r.contains("아무거나"))
+        );
+        assert!(syllable_prefix_ranges("ㅏ").is_empty());
+        assert!(syllable_prefix_ranges("ㄳ").is_empty());
+        assert_eq!(
+            syllable_prefix_ranges("힣")[0].upper_bound(),
+            Some("\u{D7A4}".to_string())
+        );
+    }
+}
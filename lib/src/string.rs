@@ -1,7 +1,10 @@
 use thiserror::Error;
 
 use crate::{
+    block::HangulBlock,
     jamo::{Jamo, JamoPosition},
+    offsets::*,
+    rules::*,
     word::*,
 };
 
@@ -59,10 +62,33 @@ pub enum StringError {
 /// composer.pop().unwrap(); // removes 'ㅂ'
 /// assert_eq!(composer.as_string().unwrap(), "바".to_string());
 /// ```
-#[derive(Debug)]
-pub struct StringComposer {
+#[derive(Debug, Clone)]
+pub struct StringComposer<R = StandardRules> {
     completed: String,
-    current: HangulWordComposer,
+    completed_offsets: OffsetMap,
+    current: HangulWordComposer<R>,
+    deletion_policy: DeletionPolicy,
+}
+
+/// How much `StringComposer::pop` removes at a time, as backspace behaves
+/// differently across platforms.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum DeletionPolicy {
+    /// Removes jamo one at a time within the word being composed, and whole
+    /// characters once a word has been completed. This is the default.
+    #[default]
+    ActiveWord,
+
+    /// Always removes jamo one at a time, reopening completed syllables so
+    /// that 한 becomes 하 rather than disappearing.
+    Jamo,
+
+    /// Always removes whole syllables, including the one being composed.
+    Syllable,
+
+    /// Removes jamo one at a time within the syllable being composed, and
+    /// whole syllables before it.
+    CurrentSyllable,
 }
 
 impl Default for StringComposer {
@@ -72,14 +98,69 @@ impl Default for StringComposer {
 }
 
 impl StringComposer {
-    /// Creates a new, empty `StringComposer`.
+    /// Creates a new, empty `StringComposer` using the `StandardRules`.
     pub fn new() -> Self {
+        Self::with_rules(StandardRules)
+    }
+}
+
+impl<R: CompositionRules> StringComposer<R> {
+    /// Creates a new, empty `StringComposer` that combines jamo according
+    /// to the given `CompositionRules`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::rules::{ExtendedClusters, NoCompositeVowels};
+    /// use hangul_cd::string::StringComposer;
+    ///
+    /// let mut composer = StringComposer::with_rules(NoCompositeVowels);
+    /// for c in "ㄱㅗㅏ".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "고ㅏ");
+    ///
+    /// let mut composer = StringComposer::with_rules(ExtendedClusters);
+    /// for c in "ㄷㅏㅅㄱ ㅇㅣㅎㄹ".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "닧 잃");
+    /// ```
+    pub fn with_rules(rules: R) -> Self {
         Self {
             completed: String::new(),
-            current: HangulWordComposer::new(),
+            completed_offsets: OffsetMap::new(),
+            current: HangulWordComposer::with_rules(rules),
+            deletion_policy: DeletionPolicy::default(),
         }
     }
 
+    /// Sets how much `pop` removes at a time.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::{DeletionPolicy, StringComposer};
+    ///
+    /// let mut composer = StringComposer::new();
+    /// composer.set_deletion_policy(DeletionPolicy::Jamo);
+    /// for c in "ㅎㅏㄴ ㄱㅡㄹ".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// composer.pop().unwrap(); // removes 'ㄹ'
+    /// composer.pop().unwrap(); // removes 'ㅡ'
+    /// composer.pop().unwrap(); // removes 'ㄱ'
+    /// composer.pop().unwrap(); // removes ' '
+    /// assert_eq!(composer.as_string().unwrap(), "한");
+    ///
+    /// // The completed syllable is reopened rather than deleted whole
+    /// composer.pop().unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "하");
+    /// composer.push_char('ㄱ').unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "학");
+    /// ```
+    pub fn set_deletion_policy(&mut self, policy: DeletionPolicy) {
+        self.deletion_policy = policy;
+    }
+
     /// Pushes a character to the `StringComposer`.
     ///
     /// If the character is part of a Hangul word, it will be composed into syllables.
@@ -91,6 +172,45 @@ impl StringComposer {
         }
     }
 
+    /// Pushes a Jamo letter to the `StringComposer` in an explicit position,
+    /// as typed on 3-beolsik layouts. See
+    /// `HangulWordComposer::push_positioned`. A letter that cannot be placed
+    /// in the current word ends the word, and then starts a new word if it
+    /// can, such as an initial typed after a lone initial; otherwise it is
+    /// added to the completed string as a lone jamo.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::jamo::{Jamo, JamoPosition};
+    /// use hangul_cd::string::StringComposer;
+    ///
+    /// let letter = |c| Jamo::from_compatibility_jamo(c).unwrap();
+    /// let mut composer = StringComposer::new();
+    /// for (c, position) in [
+    ///     ('ㄱ', JamoPosition::Initial),
+    ///     ('ㄴ', JamoPosition::Initial),
+    ///     ('ㅏ', JamoPosition::Vowel),
+    ///     ('ㄹ', JamoPosition::Final),
+    /// ] {
+    ///     composer.push_positioned(&letter(c), position).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "\u{1100}날");
+    /// ```
+    pub fn push_positioned(
+        &mut self,
+        letter: &Jamo,
+        position: JamoPosition,
+    ) -> Result<(), StringError> {
+        if self.current.push_positioned(letter, position.clone())? == WordPushResult::Continue {
+            return Ok(());
+        }
+        self.complete_word()?;
+        match self.current.push_positioned(letter, position)? {
+            WordPushResult::Continue => Ok(()),
+            _ => self.handle_invalid_input(letter.char_compatibility()),
+        }
+    }
+
     /// Returns the composed string, combining completed text and the current word.
     pub fn as_string(&self) -> Result<String, StringError> {
         let mut result = self.completed.clone();
@@ -99,37 +219,125 @@ impl StringComposer {
         Ok(result)
     }
 
+    /// Returns the `OffsetMap` between the composed string and the jamo
+    /// typed into it, with double and composite jamo split into their parts
+    /// as with `decompose_with_offsets`. Offsets are recorded as text is
+    /// completed and removed as it is popped, so each syllable covers the
+    /// jamo that were typed into it. Any other char, including a lone jamo
+    /// such as ᄁ or ㅘ, covers one jamo, so the map matches the one
+    /// `decompose_with_offsets` builds from `as_string` with composites
+    /// decomposed.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::StringComposer;
+    ///
+    /// let mut composer = StringComposer::new();
+    /// for c in "ㄱㅏㅂㅅ ㅇㅣㅆ".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "값 있");
+    ///
+    /// let map = composer.offset_map().unwrap();
+    /// assert_eq!(map.jamo_len(), 9);
+    /// assert_eq!(map.jamo_to_char(3), Some(0));
+    /// assert_eq!(map.char_to_jamo(2), Some(5));
+    /// ```
+    pub fn offset_map(&self) -> Result<OffsetMap, StringError> {
+        let mut map = self.completed_offsets.clone();
+        push_word_offsets(&mut map, &self.current)?;
+        Ok(map)
+    }
+
     /// Pops the last character from the `StringComposer` and returns it wrapped
     /// within a `Result` and `Option`.
     ///
-    /// If the current word is a Hangul word with uncompleted syllables, it will
+    /// How much is removed depends on the `DeletionPolicy`. By default, if the
+    /// current word is a Hangul word with uncompleted syllables, it will
     /// remove the last jamo from the current syllable block. Otherwise, it will
     /// remove the last character from the completed string.
     pub fn pop(&mut self) -> Result<Option<char>, StringError> {
-        match self.current.pop()? {
-            Some(c) => Ok(c.char_modern(match c {
+        let popped = match self.deletion_policy {
+            DeletionPolicy::ActiveWord | DeletionPolicy::Jamo => self.current.pop()?,
+            DeletionPolicy::CurrentSyllable => self.current.pop_within_block(),
+            DeletionPolicy::Syllable => None,
+        };
+        if let Some(c) = popped {
+            return Ok(c.char_modern(match c {
                 Jamo::Consonant(_) | Jamo::CompositeConsonant(_) => JamoPosition::Initial,
                 Jamo::Vowel(_) | Jamo::CompositeVowel(_) => JamoPosition::Vowel,
-            })),
-            None => match self.completed.pop() {
-                Some(c) => Ok(Some(c)),
-                None => Ok(None),
-            },
+            }));
+        }
+
+        if matches!(
+            self.deletion_policy,
+            DeletionPolicy::Syllable | DeletionPolicy::CurrentSyllable
+        ) && let Some(c) = self.current.pop_block()?
+        {
+            return Ok(Some(c));
+        }
+
+        match self.pop_completed() {
+            Some(c) if self.deletion_policy == DeletionPolicy::Jamo => {
+                match HangulBlock::from_char(c) {
+                    Ok(block) => {
+                        self.current.reopen_block(&block)?;
+                        self.pop()
+                    }
+                    Err(_) => Ok(Some(c)),
+                }
+            }
+            c => Ok(c),
         }
     }
 
     fn handle_invalid_input(&mut self, c: char) -> Result<(), StringError> {
+        self.complete_word()?;
+        self.completed.push(c);
+        self.completed_offsets.push(c, 1);
+        Ok(())
+    }
+
+    /// Moves the current word, as it is displayed, to the completed string.
+    fn complete_word(&mut self) -> Result<(), StringError> {
         let current_string = self.current.as_string()?;
         self.completed.push_str(&current_string);
-        self.completed.push(c);
-        self.current = HangulWordComposer::new();
+        push_word_offsets(&mut self.completed_offsets, &self.current)?;
+        self.current = HangulWordComposer::with_rules(self.current.rules().clone());
         Ok(())
     }
+
+    fn pop_completed(&mut self) -> Option<char> {
+        self.completed_offsets.pop();
+        self.completed.pop()
+    }
+}
+
+/// Appends the chars of a word being composed to an `OffsetMap`, each
+/// syllable covering the jamo typed into it and a lone jamo covering one.
+fn push_word_offsets<R: CompositionRules>(
+    map: &mut OffsetMap,
+    word: &HangulWordComposer<R>,
+) -> Result<(), WordError> {
+    for block in word.prev_blocks() {
+        let (i1, i2, v1, v2, f1, f2) = block.decomposed_tuple()?;
+        let letters: Vec<Jamo> = [i1, i2, v1, v2, f1, f2].into_iter().flatten().collect();
+        map.push(block.to_char()?, jamo_count(&letters));
+    }
+    if let Some(c) = word.cur_block().block_as_string()? {
+        let jamo = match HangulBlock::from_char(c) {
+            Ok(_) => jamo_count(&word.cur_block().letters()),
+            Err(_) => 1,
+        };
+        map.push(c, jamo);
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{block::HangulBlockDecompositionOptions, jamo::JamoUnicodeType};
 
     #[test]
     fn test_no_new_words() {
@@ -188,4 +396,164 @@ mod test {
         let result = composer.as_string().unwrap();
         assert_eq!(result, "안".to_string());
     }
+
+    /// Returns the jamo index each char starts at, followed by the jamo
+    /// length, along with the byte length.
+    fn offsets<R: CompositionRules>(composer: &StringComposer<R>) -> (Vec<usize>, usize) {
+        let map = composer.offset_map().unwrap();
+        let starts = (0..=map.char_len())
+            .map(|i| map.char_to_jamo(i).unwrap())
+            .collect();
+        (starts, map.byte_len())
+    }
+
+    #[test]
+    fn test_offset_map() {
+        let mut composer = StringComposer::new();
+        composer.set_deletion_policy(DeletionPolicy::Jamo);
+        let steps = [
+            ('ㅎ', "\u{1112}", vec![0, 1], 3),
+            ('ㅏ', "하", vec![0, 2], 3),
+            ('ㄴ', "한", vec![0, 3], 3),
+            (' ', "한 ", vec![0, 3, 4], 4),
+            ('ㄲ', "한 \u{1101}", vec![0, 3, 4, 5], 7),
+            ('ㅗ', "한 꼬", vec![0, 3, 4, 7], 7),
+            ('ㅊ', "한 꽃", vec![0, 3, 4, 8], 7),
+            ('!', "한 꽃!", vec![0, 3, 4, 8, 9], 8),
+            ('ㄷ', "한 꽃!\u{1103}", vec![0, 3, 4, 8, 9, 10], 11),
+            ('ㅏ', "한 꽃!다", vec![0, 3, 4, 8, 9, 11], 11),
+            ('ㄹ', "한 꽃!달", vec![0, 3, 4, 8, 9, 12], 11),
+            ('ㄱ', "한 꽃!닭", vec![0, 3, 4, 8, 9, 13], 11),
+            ('ㅣ', "한 꽃!달기", vec![0, 3, 4, 8, 9, 12, 14], 14),
+        ];
+        for (c, text, starts, byte_len) in steps {
+            composer.push_char(c).unwrap();
+            assert_eq!(composer.as_string().unwrap(), text);
+            assert_eq!(offsets(&composer), (starts, byte_len), "{c}");
+        }
+
+        // Popping back into the completed text removes its offsets, and a
+        // reopened syllable covers only the jamo left in it
+        let pops = [
+            ("한 꽃!달\u{1100}", vec![0, 3, 4, 8, 9, 12, 13], 14),
+            ("한 꽃!달", vec![0, 3, 4, 8, 9, 12], 11),
+            ("한 꽃!다", vec![0, 3, 4, 8, 9, 11], 11),
+            ("한 꽃!\u{1103}", vec![0, 3, 4, 8, 9, 10], 11),
+            ("한 꽃!", vec![0, 3, 4, 8, 9], 8),
+            ("한 꽃", vec![0, 3, 4, 8], 7),
+            ("한 꼬", vec![0, 3, 4, 7], 7),
+        ];
+        for (text, starts, byte_len) in pops {
+            composer.pop().unwrap();
+            assert_eq!(composer.as_string().unwrap(), text);
+            assert_eq!(offsets(&composer), (starts, byte_len), "{text}");
+        }
+        composer.push_char('ㅁ').unwrap();
+        composer.push_char('ㅇ').unwrap();
+        composer.push_char('ㅗ').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "한 꼼오");
+        assert_eq!(offsets(&composer), (vec![0, 3, 4, 8, 10], 10));
+    }
+
+    #[test]
+    fn test_offset_map_matches_decomposition() {
+        let options = HangulBlockDecompositionOptions {
+            decompose_composites: true,
+            jamo_era: JamoUnicodeType::Compatibility,
+        };
+        for input in [
+            "ㄱㄱ",
+            "ㅗㅏ",
+            "ㄱㄱ ㅗㅏ",
+            "ㄱㅏㅂㅅ ㄲ",
+            "ㄷㅏㄹㄱ ㅘ!",
+            "ㅉㅣㅂ ㅃ",
+        ] {
+            let mut composer = StringComposer::new();
+            for c in input.chars() {
+                composer.push_char(c).unwrap();
+            }
+            let text = composer.as_string().unwrap();
+            let (_, expected) = decompose_with_offsets(&text, &options).unwrap();
+            assert_eq!(composer.offset_map().unwrap(), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_positioned_initials() {
+        let letter = |c| Jamo::from_compatibility_jamo(c).unwrap();
+        let tests = [
+            ("ㄱㄴㅏ", "\u{1100}나"),
+            ("ㄲㄴㅏ", "\u{1101}나"),
+            ("ㄱㄱㄴㅏ", "\u{1101}나"),
+            ("ㄱㄴㄷㅏ", "\u{1100}\u{1102}다"),
+        ];
+        for (input, expected) in tests {
+            let mut composer = StringComposer::new();
+            for c in input.chars() {
+                let position = match letter(c) {
+                    Jamo::Vowel(_) | Jamo::CompositeVowel(_) => JamoPosition::Vowel,
+                    _ => JamoPosition::Initial,
+                };
+                composer.push_positioned(&letter(c), position).unwrap();
+            }
+            assert_eq!(composer.as_string().unwrap(), expected, "{input}");
+            let options = HangulBlockDecompositionOptions {
+                decompose_composites: true,
+                jamo_era: JamoUnicodeType::Compatibility,
+            };
+            let (_, map) = decompose_with_offsets(expected, &options).unwrap();
+            assert_eq!(composer.offset_map().unwrap(), map, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_deletion_policies() {
+        let tests = [
+            (
+                DeletionPolicy::ActiveWord,
+                vec![
+                    "한 글\u{110A}",
+                    "한 글",
+                    "한 그",
+                    "한 \u{1100}",
+                    "한 ",
+                    "한",
+                    "",
+                ],
+            ),
+            (
+                DeletionPolicy::Jamo,
+                vec![
+                    "한 글\u{110A}",
+                    "한 글",
+                    "한 그",
+                    "한 \u{1100}",
+                    "한 ",
+                    "한",
+                    "하",
+                    "\u{1112}",
+                    "",
+                ],
+            ),
+            (DeletionPolicy::Syllable, vec!["한 글", "한 ", "한", ""]),
+            (
+                DeletionPolicy::CurrentSyllable,
+                vec!["한 글\u{110A}", "한 글", "한 ", "한", ""],
+            ),
+        ];
+        for (policy, expected) in tests {
+            let mut composer = StringComposer::new();
+            composer.set_deletion_policy(policy.clone());
+            for c in "ㅎㅏㄴ ㄱㅡㄹㅆㅣ".chars() {
+                composer.push_char(c).unwrap();
+            }
+            assert_eq!(composer.as_string().unwrap(), "한 글씨");
+            let mut shown = Vec::new();
+            while composer.pop().unwrap().is_some() {
+                shown.push(composer.as_string().unwrap());
+            }
+            assert_eq!(shown, expected, "{policy:?}");
+        }
+    }
 }
@@ -0,0 +1,372 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{jamo::*, search::*};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum TrieKey {
+    Letter(Jamo),
+    Other(char),
+}
+
+#[derive(Debug, Clone)]
+struct TrieNode {
+    children: HashMap<TrieKey, TrieNode>,
+    entries: Vec<(String, f64)>,
+
+    /// The highest score of any entry in this subtree, used to search the
+    /// trie best-first.
+    best: f64,
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        TrieNode {
+            children: HashMap::new(),
+            entries: Vec::new(),
+            best: f64::NEG_INFINITY,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.children.is_empty() && self.entries.is_empty()
+    }
+
+    fn insert(&mut self, keys: &[TrieKey], word: &str, score: f64) {
+        if score.total_cmp(&self.best) == Ordering::Greater {
+            self.best = score;
+        }
+        match keys.split_first() {
+            Some((key, rest)) => self
+                .children
+                .entry(key.clone())
+                .or_insert_with(TrieNode::new)
+                .insert(rest, word, score),
+            None => self.entries.push((word.to_string(), score)),
+        }
+    }
+
+    fn remove(&mut self, keys: &[TrieKey], word: &str) -> Option<f64> {
+        let removed = match keys.split_first() {
+            Some((key, rest)) => {
+                let child = self.children.get_mut(key)?;
+                let removed = child.remove(rest, word)?;
+                if child.is_empty() {
+                    self.children.remove(key);
+                }
+                removed
+            }
+            None => {
+                let index = self.entries.iter().position(|(w, _)| w == word)?;
+                self.entries.swap_remove(index).1
+            }
+        };
+        self.best = self
+            .entries
+            .iter()
+            .map(|(_, score)| *score)
+            .chain(self.children.values().map(|child| child.best))
+            .max_by(f64::total_cmp)
+            .unwrap_or(f64::NEG_INFINITY);
+        Some(removed)
+    }
+
+    fn get(&self, keys: &[TrieKey]) -> Option<&TrieNode> {
+        keys.iter()
+            .try_fold(self, |node, key| node.children.get(key))
+    }
+}
+
+/// An item waiting to be visited during a best-first search of the trie.
+enum Pending<'a> {
+    Node(&'a TrieNode),
+    Entry(&'a str, f64),
+}
+
+impl Pending<'_> {
+    fn score(&self) -> f64 {
+        match self {
+            Pending::Node(node) => node.best,
+            Pending::Entry(_, score) => *score,
+        }
+    }
+}
+
+impl PartialEq for Pending<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pending<'_> {}
+
+impl PartialOrd for Pending<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pending<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Entries are visited before nodes with the same score, so results
+        // are returned as soon as nothing better can follow
+        self.score()
+            .total_cmp(&other.score())
+            .then_with(|| match (self, other) {
+                (Pending::Entry(..), Pending::Node(_)) => Ordering::Greater,
+                (Pending::Node(_), Pending::Entry(..)) => Ordering::Less,
+                _ => Ordering::Equal,
+            })
+    }
+}
+
+/// An autocomplete index over Hangul words, keyed on their jamo rather than
+/// on whole syllables so that partially typed syllables can be looked up.
+///
+/// Each word is stored with a caller-supplied score, such as a popularity
+/// count, and queries return the `k` best-scoring matches. The trie keeps the
+/// best score of every subtree, so only the branches that can still produce
+/// a top result are visited.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::trie::JamoTrie;
+///
+/// let mut trie = JamoTrie::new();
+/// trie.insert("한국", 5.0);
+/// trie.insert("한글", 9.0);
+/// trie.insert("학교", 7.0);
+/// trie.insert("가방", 3.0);
+///
+/// // The last syllable of the query may still be unfinished
+/// assert_eq!(trie.top_k("하", 2), vec![("한글", 9.0), ("학교", 7.0)]);
+/// assert_eq!(trie.top_k("갑", 10), vec![("가방", 3.0)]);
+///
+/// // Queries can also be made of initial consonants only
+/// assert_eq!(trie.choseong_top_k("ㅎㄱ", 2), vec![("한글", 9.0), ("학교", 7.0)]);
+///
+/// assert_eq!(trie.remove("한글"), Some(9.0));
+/// assert_eq!(trie.top_k("한", 10), vec![("한국", 5.0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct JamoTrie {
+    letters: TrieNode,
+    initials: TrieNode,
+    len: usize,
+}
+
+impl Default for JamoTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JamoTrie {
+    /// Creates a new, empty `JamoTrie`.
+    pub fn new() -> Self {
+        JamoTrie {
+            letters: TrieNode::new(),
+            initials: TrieNode::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of words in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the trie contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a word with the given score, returning the previous score if
+    /// the word was already present.
+    pub fn insert(&mut self, word: &str, score: f64) -> Option<f64> {
+        let previous = self.remove(word);
+        self.letters.insert(&letter_keys(word), word, score);
+        self.initials.insert(&initial_keys(word), word, score);
+        self.len += 1;
+        previous
+    }
+
+    /// Removes a word from the trie, returning its score if it was present.
+    pub fn remove(&mut self, word: &str) -> Option<f64> {
+        let score = self.letters.remove(&letter_keys(word), word)?;
+        self.initials.remove(&initial_keys(word), word);
+        self.len -= 1;
+        Some(score)
+    }
+
+    /// Returns the score of a word, or `None` if it is not in the trie.
+    pub fn score(&self, word: &str) -> Option<f64> {
+        self.letters
+            .get(&letter_keys(word))?
+            .entries
+            .iter()
+            .find(|(w, _)| w == word)
+            .map(|(_, score)| *score)
+    }
+
+    /// Returns up to `k` words that could be the result of continuing to type
+    /// the query, ordered by descending score. The last syllable of the query
+    /// is matched jamo by jamo as with `IncrementalMatcher`, so "하" finds
+    /// 한국 and "갑" finds 가방.
+    pub fn top_k(&self, query: &str, k: usize) -> Vec<(&str, f64)> {
+        let matcher = IncrementalMatcher::new(query);
+        match self.letters.get(&letter_keys(query)) {
+            Some(node) => best_first(node, k, |word| matcher.is_match(word)),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns up to `k` words whose initial consonants (초성) start with the
+    /// query, ordered by descending score. Whitespace is ignored, and the
+    /// query may mix initials with full syllables as with `ChoseongMatcher`.
+    pub fn choseong_top_k(&self, query: &str, k: usize) -> Vec<(&str, f64)> {
+        let matcher = ChoseongMatcher::new(query, ChoseongMatchOptions::default());
+        match self.initials.get(&initial_keys(query)) {
+            Some(node) => best_first(node, k, |word| {
+                matcher.find(word).is_some_and(|range| range.start == 0)
+            }),
+            None => Vec::new(),
+        }
+    }
+}
+
+fn best_first(root: &TrieNode, k: usize, is_match: impl Fn(&str) -> bool) -> Vec<(&str, f64)> {
+    let mut result = Vec::new();
+    let mut heap = BinaryHeap::from([Pending::Node(root)]);
+    while result.len() < k {
+        match heap.pop() {
+            Some(Pending::Entry(word, score)) => {
+                if is_match(word) {
+                    result.push((word, score));
+                }
+            }
+            Some(Pending::Node(node)) => {
+                heap.extend(
+                    node.entries
+                        .iter()
+                        .map(|(word, score)| Pending::Entry(word, *score)),
+                );
+                heap.extend(node.children.values().map(Pending::Node));
+            }
+            None => break,
+        }
+    }
+    result
+}
+
+fn letter_keys(s: &str) -> Vec<TrieKey> {
+    s.chars()
+        .flat_map(|c| match typed_letters(c) {
+            Some(letters) => letters.into_iter().map(TrieKey::Letter).collect(),
+            None => vec![TrieKey::Other(c)],
+        })
+        .collect()
+}
+
+fn initial_keys(s: &str) -> Vec<TrieKey> {
+    s.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(|c| match choseong(c) {
+            Some(initial) => vec![TrieKey::Letter(initial)],
+            None => c.to_lowercase().map(TrieKey::Other).collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> JamoTrie {
+        let mut trie = JamoTrie::new();
+        for (word, score) in [
+            ("사과", 4.0),
+            ("사과즙", 2.0),
+            ("사랑", 8.0),
+            ("살구", 6.0),
+            ("각가", 1.0),
+            ("가까", 3.0),
+            ("Galaxy 폰", 5.0),
+        ] {
+            trie.insert(word, score);
+        }
+        trie
+    }
+
+    #[test]
+    fn prefix_queries() {
+        let trie = sample();
+        let tests = vec![
+            ("사", vec!["사랑", "살구", "사과", "사과즙"]),
+            ("살", vec!["사랑", "살구"]),
+            ("사고", vec!["사과", "사과즙"]),
+            ("사과", vec!["사과", "사과즙"]),
+            ("각", vec!["가까", "각가"]),
+            ("각가", vec!["각가"]),
+            ("Galaxy ", vec!["Galaxy 폰"]),
+            ("수", vec![]),
+        ];
+        for (query, expected) in tests {
+            let words: Vec<&str> = trie.top_k(query, 10).into_iter().map(|(w, _)| w).collect();
+            assert_eq!(words, expected, "{query}");
+        }
+    }
+
+    #[test]
+    fn top_k_limits_results() {
+        let trie = sample();
+        assert_eq!(trie.top_k("", 2), vec![("사랑", 8.0), ("살구", 6.0)]);
+        assert_eq!(trie.top_k("사", 0), vec![]);
+    }
+
+    #[test]
+    fn choseong_queries() {
+        let trie = sample();
+        let words: Vec<&str> = trie
+            .choseong_top_k("ㅅㄱ", 10)
+            .into_iter()
+            .map(|(w, _)| w)
+            .collect();
+        assert_eq!(words, vec!["살구", "사과", "사과즙"]);
+        assert_eq!(
+            trie.choseong_top_k("ㅅ과", 10),
+            vec![("사과", 4.0), ("사과즙", 2.0)]
+        );
+        assert_eq!(trie.choseong_top_k("gㄱ", 10), vec![]);
+        assert_eq!(
+            trie.choseong_top_k("galaxyㅍ", 10),
+            vec![("Galaxy 폰", 5.0)]
+        );
+        assert_eq!(trie.choseong_top_k("ㄱㄲ", 10), vec![("가까", 3.0)]);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut trie = sample();
+        assert_eq!(trie.len(), 7);
+        assert_eq!(trie.insert("사랑", 1.0), Some(8.0));
+        assert_eq!(trie.len(), 7);
+        assert_eq!(trie.top_k("사", 1), vec![("살구", 6.0)]);
+
+        assert_eq!(trie.remove("살구"), Some(6.0));
+        assert_eq!(trie.remove("살구"), None);
+        assert_eq!(trie.score("살구"), None);
+        assert_eq!(trie.score("사과"), Some(4.0));
+        assert_eq!(trie.top_k("사", 1), vec![("사과", 4.0)]);
+        assert_eq!(trie.choseong_top_k("ㅅㄱ", 10).len(), 2);
+
+        for word in ["사과", "사과즙", "사랑", "각가", "가까", "Galaxy 폰"] {
+            assert!(trie.remove(word).is_some());
+        }
+        assert!(trie.is_empty());
+        assert!(trie.letters.is_empty());
+        assert!(trie.initials.is_empty());
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use thiserror::Error;
 
-use crate::{block::*, jamo::*};
+use crate::{block::*, jamo::*, rules::*};
 
 /// A composer for a single Hangul word, made up of multiple syllable blocks.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -67,10 +67,10 @@ pub enum WordError {
 /// );
 /// assert_eq!(composer.as_string().unwrap(), "안".to_string());
 /// ```
-#[derive(Debug)]
-pub struct HangulWordComposer {
+#[derive(Debug, Clone)]
+pub struct HangulWordComposer<R = StandardRules> {
     prev_blocks: Vec<HangulBlock>,
-    cur_block: BlockComposer,
+    cur_block: BlockComposer<R>,
 }
 
 impl Default for HangulWordComposer {
@@ -94,11 +94,19 @@ pub enum WordPushResult {
 }
 
 impl HangulWordComposer {
-    /// Creates a new, empty `HangulWordComposer`.
+    /// Creates a new, empty `HangulWordComposer` using the `StandardRules`.
     pub fn new() -> Self {
+        Self::with_rules(StandardRules)
+    }
+}
+
+impl<R: CompositionRules> HangulWordComposer<R> {
+    /// Creates a new, empty `HangulWordComposer` that combines jamo
+    /// according to the given `CompositionRules`.
+    pub fn with_rules(rules: R) -> Self {
         HangulWordComposer {
             prev_blocks: Vec::new(),
-            cur_block: BlockComposer::new(),
+            cur_block: BlockComposer::with_rules(rules),
         }
     }
 
@@ -147,6 +155,53 @@ impl HangulWordComposer {
         }
     }
 
+    /// Pushes a Jamo letter into the `HangulWordComposer` in an explicit
+    /// position, for 3-beolsik input where initial and final consonants are
+    /// typed with separate keys. An initial that does not fit the current
+    /// block always starts a new block, and a final is never moved to the
+    /// next block, so syllables are built exactly as typed.
+    ///
+    /// Returns `WordPushResult::InvalidHangul` if the letter cannot be placed
+    /// in the given position, such as a final with no vowel before it, or an
+    /// initial typed after a lone initial it does not combine with.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::jamo::{Jamo, JamoPosition};
+    /// use hangul_cd::word::HangulWordComposer;
+    ///
+    /// let letter = |c| Jamo::from_compatibility_jamo(c).unwrap();
+    /// let mut composer = HangulWordComposer::new();
+    /// for (c, position) in [
+    ///     ('ㄱ', JamoPosition::Initial),
+    ///     ('ㅏ', JamoPosition::Vowel),
+    ///     ('ㄱ', JamoPosition::Initial),
+    ///     ('ㅏ', JamoPosition::Vowel),
+    ///     ('ㅂ', JamoPosition::Final),
+    ///     ('ㅅ', JamoPosition::Final),
+    /// ] {
+    ///     composer.push_positioned(&letter(c), position).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "가값");
+    /// ```
+    pub fn push_positioned(
+        &mut self,
+        letter: &Jamo,
+        position: JamoPosition,
+    ) -> Result<WordPushResult, WordError> {
+        match self.cur_block.push_positioned(letter, position) {
+            BlockPushResult::Success => Ok(WordPushResult::Continue),
+            BlockPushResult::StartNewBlockNoPop => {
+                self.start_new_block(letter.clone())?;
+                Ok(WordPushResult::Continue)
+            }
+            BlockPushResult::NonHangul => Ok(WordPushResult::NonHangul),
+            BlockPushResult::InvalidHangul | BlockPushResult::PopAndStartNewBlock => {
+                Ok(WordPushResult::InvalidHangul)
+            }
+        }
+    }
+
     /// Pops the last Jamo letter from the `HangulWordComposer`.
     /// If the current syllable block has letters, it will remove the last
     /// letter from it. If the current block is empty, it will set the last
@@ -170,9 +225,56 @@ impl HangulWordComposer {
         }
     }
 
+    /// Pops the last Jamo letter from the current syllable block only,
+    /// without reopening the previous block once it is empty.
+    pub(crate) fn pop_within_block(&mut self) -> Option<Jamo> {
+        match self.cur_block.pop() {
+            BlockPopStatus::PoppedAndNonEmpty(l) | BlockPopStatus::PoppedAndEmpty(l) => Some(l),
+            BlockPopStatus::None => None,
+        }
+    }
+
+    /// Removes the last syllable of the word, the current block if it has
+    /// letters or the last completed block otherwise, and returns it as it
+    /// was displayed.
+    pub(crate) fn pop_block(&mut self) -> Result<Option<char>, WordError> {
+        if let Some(c) = self.cur_block.block_as_string()? {
+            self.cur_block = BlockComposer::with_rules(self.rules().clone());
+            return Ok(Some(c));
+        }
+        match self.prev_blocks.pop() {
+            Some(block) => Ok(Some(block.to_char()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Makes a completed syllable the current block of an empty word, so
+    /// that its letters can be removed or added to one at a time.
+    pub(crate) fn reopen_block(&mut self, block: &HangulBlock) -> Result<(), WordError> {
+        self.prev_blocks.clear();
+        self.cur_block = BlockComposer::from_block_with_rules(block, self.rules().clone())?;
+        Ok(())
+    }
+
+    /// Returns the syllable blocks that have already been completed.
+    pub(crate) fn prev_blocks(&self) -> &Vec<HangulBlock> {
+        &self.prev_blocks
+    }
+
+    /// Returns the composer for the syllable block currently being typed.
+    pub(crate) fn cur_block(&self) -> &BlockComposer<R> {
+        &self.cur_block
+    }
+
+    /// Returns the rules the composer combines jamo with.
+    pub(crate) fn rules(&self) -> &R {
+        self.cur_block.rules()
+    }
+
     fn prev_block_to_cur(&mut self) -> Result<(), WordError> {
         if let Some(last_block) = self.prev_blocks.pop() {
-            self.cur_block = BlockComposer::from_composed_block(&last_block)?;
+            self.cur_block =
+                BlockComposer::from_block_with_rules(&last_block, self.rules().clone())?;
             Ok(())
         } else {
             Ok(())
@@ -223,7 +325,7 @@ impl HangulWordComposer {
         match self.cur_block.try_as_complete_block()? {
             BlockCompletionStatus::Complete(block) => {
                 self.prev_blocks.push(block);
-                self.cur_block = BlockComposer::new();
+                self.cur_block = BlockComposer::with_rules(self.rules().clone());
                 Ok(())
             }
             BlockCompletionStatus::Incomplete(c) => Err(WordError::CannotCompleteCurrentBlock(c)),
@@ -428,6 +530,43 @@ mod tests {
         assert_eq!(composer.as_string().unwrap(), "안".to_string());
     }
 
+    #[test]
+    fn positioned_initials_after_lone_initial() {
+        let letter = |c| Jamo::from_compatibility_jamo(c).unwrap();
+        let initial = JamoPosition::Initial;
+
+        let mut composer = HangulWordComposer::new();
+        assert_eq!(
+            composer.push_positioned(&letter('ㄱ'), initial.clone()),
+            Ok(WordPushResult::Continue)
+        );
+        assert_eq!(
+            composer.push_positioned(&letter('ㄴ'), initial.clone()),
+            Ok(WordPushResult::InvalidHangul)
+        );
+        assert_eq!(composer.as_string().unwrap(), "\u{1100}");
+
+        // The same holds once the initial is a double consonant, whether it
+        // was typed as one letter or made from two
+        for typed in [vec!['ㄲ'], vec!['ㄱ', 'ㄱ']] {
+            let mut composer = HangulWordComposer::new();
+            for c in typed {
+                composer
+                    .push_positioned(&letter(c), initial.clone())
+                    .unwrap();
+            }
+            assert_eq!(
+                composer.push_positioned(&letter('ㄴ'), initial.clone()),
+                Ok(WordPushResult::InvalidHangul)
+            );
+            assert_eq!(
+                composer.push_positioned(&letter('ㄸ'), initial.clone()),
+                Ok(WordPushResult::InvalidHangul)
+            );
+            assert_eq!(composer.as_string().unwrap(), "\u{1101}");
+        }
+    }
+
     #[test]
     fn test_complete_current_block() {
         let mut composer = HangulWordComposer::new();
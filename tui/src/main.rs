@@ -1,5 +1,6 @@
 use color_eyre::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use hangul_cd::layout::{Dubeolsik, KeyOutput, KeyboardLayout};
 use hangul_cd::string::StringComposer;
 use ratatui::layout::{Constraint, Layout};
 use ratatui::style::{Style, Stylize};
@@ -63,12 +64,19 @@ impl App {
     }
 
     fn handle_char(&mut self, key_char: char) {
-        let input = match map_key_to_jamo(key_char) {
-            Some(c) => c,
-            None => key_char,
+        let (input, result) = match Dubeolsik.map_char(key_char) {
+            KeyOutput::Jamo(jamo) => {
+                let input = jamo.char_compatibility();
+                (input, self.composer.push_char(input))
+            }
+            KeyOutput::PositionedJamo(jamo, position) => (
+                jamo.char_compatibility(),
+                self.composer.push_positioned(&jamo, position),
+            ),
+            KeyOutput::Passthrough(c) => (c, self.composer.push_char(c)),
         };
 
-        match self.composer.push_char(input) {
+        match result {
             Ok(()) => {
                 self.status = format!("Added '{input}'");
             }
@@ -79,47 +87,6 @@ impl App {
     }
 }
 
-fn map_key_to_jamo(key_char: char) -> Option<char> {
-    match key_char {
-        // Consonants (2-beolsik)
-        'r' => Some('ㄱ'),
-        'R' => Some('ㄲ'),
-        's' => Some('ㄴ'),
-        'e' => Some('ㄷ'),
-        'E' => Some('ㄸ'),
-        'f' => Some('ㄹ'),
-        'a' => Some('ㅁ'),
-        'q' => Some('ㅂ'),
-        'Q' => Some('ㅃ'),
-        't' => Some('ㅅ'),
-        'T' => Some('ㅆ'),
-        'd' => Some('ㅇ'),
-        'w' => Some('ㅈ'),
-        'W' => Some('ㅉ'),
-        'c' => Some('ㅊ'),
-        'z' => Some('ㅋ'),
-        'x' => Some('ㅌ'),
-        'v' => Some('ㅍ'),
-        'g' => Some('ㅎ'),
-        // Vowels (2-beolsik)
-        'k' => Some('ㅏ'),
-        'o' => Some('ㅐ'),
-        'O' => Some('ㅒ'),
-        'i' => Some('ㅑ'),
-        'j' => Some('ㅓ'),
-        'p' => Some('ㅔ'),
-        'P' => Some('ㅖ'),
-        'u' => Some('ㅕ'),
-        'h' => Some('ㅗ'),
-        'y' => Some('ㅛ'),
-        'n' => Some('ㅜ'),
-        'b' => Some('ㅠ'),
-        'm' => Some('ㅡ'),
-        'l' => Some('ㅣ'),
-        _ => None,
-    }
-}
-
 fn handle_key(app: &mut App, key: KeyEvent) -> bool {
     if key.kind != KeyEventKind::Press {
         return false;